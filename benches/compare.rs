@@ -0,0 +1,336 @@
+//! Compares the ring buffer with `rtrb`, the bounded channel of
+//! `crossbeam-channel` and the bounded channel of the standard library on the
+//! same single-element and bulk workloads, across capacities and element
+//! sizes, as well as with `RingBufferAny` to measure the overhead of
+//! supporting capacities that are not a power of two, and with
+//! `LocalRingBuffer` to measure the overhead of the atomics when a single
+//! thread both pushes and pulls.
+//!
+//! Each iteration transfers one element, so that criterion reports the time
+//! per operation along with the throughput in elements per second.
+//!
+//! Run with `cargo bench --bench compare`.
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ringbuffer_spsc::{LocalRingBuffer, RingBuffer, RingBufferAny};
+use std::hint::black_box;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const BATCH: usize = 64;
+
+/// Transfers `elems` elements between two threads and returns how long it took.
+type Workload = fn(usize) -> Duration;
+
+fn ring_single<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = RingBuffer::<T, N>::init();
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems {
+            while tx.push(T::default()).is_some() {
+                thread::yield_now();
+            }
+        }
+    });
+    for _ in 0..elems {
+        loop {
+            if let Some(t) = rx.pull() {
+                black_box(t);
+                break;
+            }
+            thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn ring_bulk<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = RingBuffer::<T, N>::init();
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        let src = [T::default(); BATCH];
+        let mut sent = 0;
+        while sent < elems {
+            let n = (elems - sent).min(BATCH);
+            let pushed = tx.push_slice(&src[..n]);
+            if pushed == 0 {
+                thread::yield_now();
+            }
+            sent += pushed;
+        }
+    });
+    let mut dst = [T::default(); BATCH];
+    let mut received = 0;
+    while received < elems {
+        let pulled = rx.pull_slice(&mut dst);
+        if pulled == 0 {
+            thread::yield_now();
+        }
+        black_box(&dst);
+        received += pulled;
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn any_single<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = RingBufferAny::<T>::init(N).unwrap();
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems {
+            while tx.push(T::default()).is_some() {
+                thread::yield_now();
+            }
+        }
+    });
+    for _ in 0..elems {
+        loop {
+            if let Some(t) = rx.pull() {
+                black_box(t);
+                break;
+            }
+            thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn ring_local<T: Copy + Default, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = RingBuffer::<T, N>::init();
+    let start = Instant::now();
+    for _ in 0..elems / N {
+        for _ in 0..N {
+            assert!(tx.push(black_box(T::default())).is_none());
+        }
+        for _ in 0..N {
+            black_box(rx.pull());
+        }
+    }
+    start.elapsed()
+}
+
+fn local_local<T: Copy + Default, const N: usize>(elems: usize) -> Duration {
+    let mut rb = LocalRingBuffer::<T>::new(N);
+    let start = Instant::now();
+    for _ in 0..elems / N {
+        for _ in 0..N {
+            assert!(rb.push(black_box(T::default())).is_none());
+        }
+        for _ in 0..N {
+            black_box(rb.pull());
+        }
+    }
+    start.elapsed()
+}
+
+fn rtrb_single<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = rtrb::RingBuffer::<T>::new(N);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems {
+            while tx.push(T::default()).is_err() {
+                thread::yield_now();
+            }
+        }
+    });
+    for _ in 0..elems {
+        loop {
+            if let Ok(t) = rx.pop() {
+                black_box(t);
+                break;
+            }
+            thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn rtrb_bulk<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (mut tx, mut rx) = rtrb::RingBuffer::<T>::new(N);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        let src = [T::default(); BATCH];
+        let mut sent = 0;
+        while sent < elems {
+            let n = (elems - sent).min(BATCH);
+            let (pushed, _) = tx.push_partial_slice(&src[..n]);
+            if pushed.is_empty() {
+                thread::yield_now();
+            }
+            sent += pushed.len();
+        }
+    });
+    let mut dst = [T::default(); BATCH];
+    let mut received = 0;
+    while received < elems {
+        let (pulled, _) = rx.pop_partial_slice(&mut dst);
+        let pulled = pulled.len();
+        if pulled == 0 {
+            thread::yield_now();
+        }
+        black_box(&dst);
+        received += pulled;
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn crossbeam_single<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (tx, rx) = crossbeam_channel::bounded::<T>(N);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems {
+            tx.send(T::default()).unwrap();
+        }
+    });
+    for _ in 0..elems {
+        black_box(rx.recv().unwrap());
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn crossbeam_bulk<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    // The channel has no bulk API: send whole batches as single elements
+    let (tx, rx) = crossbeam_channel::bounded::<[T; BATCH]>(N / BATCH);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems / BATCH {
+            tx.send([T::default(); BATCH]).unwrap();
+        }
+    });
+    for _ in 0..elems / BATCH {
+        black_box(rx.recv().unwrap());
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn std_single<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    let (tx, rx) = mpsc::sync_channel::<T>(N);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems {
+            tx.send(T::default()).unwrap();
+        }
+    });
+    for _ in 0..elems {
+        black_box(rx.recv().unwrap());
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn std_bulk<T: Copy + Default + Send + 'static, const N: usize>(elems: usize) -> Duration {
+    // The channel has no bulk API: send whole batches as single elements
+    let (tx, rx) = mpsc::sync_channel::<[T; BATCH]>(N / BATCH);
+    let start = Instant::now();
+    let p = thread::spawn(move || {
+        for _ in 0..elems / BATCH {
+            tx.send([T::default(); BATCH]).unwrap();
+        }
+    });
+    for _ in 0..elems / BATCH {
+        black_box(rx.recv().unwrap());
+    }
+    p.join().unwrap();
+    start.elapsed()
+}
+
+fn report(c: &mut Criterion, group: &str, name: &str, case: &str, f: Workload) {
+    let mut group = c.benchmark_group(group);
+    group.throughput(Throughput::Elements(1));
+    group.bench_function(BenchmarkId::new(name, case), |b| {
+        b.iter_custom(|iters| f(iters as usize))
+    });
+    group.finish();
+}
+
+macro_rules! compare {
+    ($c:expr, $t:ty, $n:literal) => {
+        let case = concat!(stringify!($t), ", N = ", $n);
+        report($c, "single", "ringbuffer-spsc", case, ring_single::<$t, $n>);
+        report(
+            $c,
+            "single",
+            "ringbuffer-spsc any",
+            case,
+            any_single::<$t, $n>,
+        );
+        report($c, "single", "rtrb", case, rtrb_single::<$t, $n>);
+        report(
+            $c,
+            "single",
+            "crossbeam-channel",
+            case,
+            crossbeam_single::<$t, $n>,
+        );
+        report(
+            $c,
+            "single",
+            "std::sync_channel",
+            case,
+            std_single::<$t, $n>,
+        );
+        report($c, "bulk", "ringbuffer-spsc", case, ring_bulk::<$t, $n>);
+        report($c, "bulk", "rtrb", case, rtrb_bulk::<$t, $n>);
+        report(
+            $c,
+            "bulk",
+            "crossbeam-channel",
+            case,
+            crossbeam_bulk::<$t, $n>,
+        );
+        report($c, "bulk", "std::sync_channel", case, std_bulk::<$t, $n>);
+    };
+}
+
+fn compare(c: &mut Criterion) {
+    compare!(c, u64, 64);
+    compare!(c, u64, 1024);
+    compare!(c, [u64; 8], 64);
+    compare!(c, [u64; 8], 1024);
+    // A single thread pushing and pulling
+    let case = "u64, N = 64";
+    report(
+        c,
+        "same thread",
+        "ringbuffer-spsc",
+        case,
+        ring_local::<u64, 64>,
+    );
+    report(
+        c,
+        "same thread",
+        "LocalRingBuffer",
+        case,
+        local_local::<u64, 64>,
+    );
+    // Capacities that are not a power of two are only supported by RingBufferAny
+    report(
+        c,
+        "single",
+        "ringbuffer-spsc any",
+        "u64, N = 48",
+        any_single::<u64, 48>,
+    );
+    report(
+        c,
+        "single",
+        "ringbuffer-spsc any",
+        "u64, N = 1000",
+        any_single::<u64, 1000>,
+    );
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(3));
+    targets = compare
+}
+criterion_main!(benches);
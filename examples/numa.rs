@@ -0,0 +1,47 @@
+//! Measures the throughput of a ring buffer bound to a given NUMA node.
+//! Run it with the consumer pinned to different nodes, e.g.
+//! `numactl --cpunodebind=1 cargo run --release --example numa --features numa -- 0`,
+//! to compare cross-node and same-node throughput.
+use ringbuffer_spsc::RingBuffer;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+fn main() {
+    let node: u32 = std::env::args()
+        .nth(1)
+        .map(|n| n.parse().expect("Invalid NUMA node"))
+        .unwrap_or(0);
+    let (mut tx, mut rx) = RingBuffer::<usize, 4096>::init_on_node(node).unwrap();
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn(move || {
+        let mut current: usize = 0;
+        loop {
+            if tx.push(current).is_none() {
+                current = current.wrapping_add(1);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let c_counter = counter.clone();
+    std::thread::spawn(move || {
+        let mut current: usize = 0;
+        loop {
+            if let Some(c) = rx.pull() {
+                assert_eq!(c, current);
+                current = current.wrapping_add(1);
+                c_counter.fetch_add(1, Ordering::Relaxed);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+        println!("node {node}: {} elem/s", counter.swap(0, Ordering::Relaxed));
+    }
+}
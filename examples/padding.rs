@@ -0,0 +1,47 @@
+//! Compares the throughput of a tiny ring buffer of `u32` with and without
+//! padding each element to its own cache line.
+//! Run it with `cargo run --release --example padding`.
+use ringbuffer_spsc::RingBuffer;
+use std::time::{Duration, Instant};
+
+const DURATION: Duration = Duration::from_secs(2);
+
+fn run(
+    mut push: impl FnMut(u32) -> Option<u32> + Send + 'static,
+    mut pull: impl FnMut() -> Option<u32>,
+) -> usize {
+    let start = Instant::now();
+    std::thread::spawn(move || {
+        let mut current: u32 = 0;
+        while start.elapsed() < DURATION {
+            if push(current).is_none() {
+                current = current.wrapping_add(1);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let mut current: u32 = 0;
+    let mut count = 0;
+    while start.elapsed() < DURATION {
+        if let Some(c) = pull() {
+            assert_eq!(c, current);
+            current = current.wrapping_add(1);
+            count += 1;
+        } else {
+            std::thread::yield_now();
+        }
+    }
+    count
+}
+
+fn main() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 4>::init();
+    let plain = run(move |t| tx.push(t), move || rx.pull());
+    println!("plain:  {} elem/s", plain / DURATION.as_secs() as usize);
+
+    let (mut tx, mut rx) = RingBuffer::<u32, 4>::init_padded();
+    let padded = run(move |t| tx.push(t), move || rx.pull());
+    println!("padded: {} elem/s", padded / DURATION.as_secs() as usize);
+}
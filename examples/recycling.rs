@@ -0,0 +1,48 @@
+//! Circulates a pool of preallocated buffers between a producer filling them
+//! and a consumer checking them, without any allocation in the loop.
+use ringbuffer_spsc::RingBuffer;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const BUF_SIZE: usize = 4096;
+
+fn main() {
+    let pool = (0..8).map(|_| Vec::<u8>::with_capacity(BUF_SIZE));
+    let (mut producer, mut consumer) = RingBuffer::<Vec<u8>, 8>::init_recycling(pool);
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    std::thread::spawn(move || {
+        let mut current: u8 = 0;
+        loop {
+            if let Some(mut buf) = producer.get_empty() {
+                buf.clear();
+                buf.resize(BUF_SIZE, current);
+                assert!(producer.submit_full(buf).is_none());
+                current = current.wrapping_add(1);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let c_counter = counter.clone();
+    std::thread::spawn(move || {
+        let mut current: u8 = 0;
+        loop {
+            if let Some(buf) = consumer.get_full() {
+                assert!(buf.iter().all(|b| *b == current));
+                assert!(consumer.return_empty(buf).is_none());
+                current = current.wrapping_add(1);
+                c_counter.fetch_add(1, Ordering::Relaxed);
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    loop {
+        std::thread::sleep(Duration::from_secs(1));
+        println!("{} buf/s", counter.swap(0, Ordering::Relaxed));
+    }
+}
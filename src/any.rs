@@ -0,0 +1,225 @@
+use crate::{CachePadded, CapacityError};
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    cell::UnsafeCell,
+    mem::{self, MaybeUninit},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// A ring buffer whose capacity is chosen at runtime and is not required to
+/// be a power of two, e.g. when the capacity comes from a configuration and
+/// rounding it up would waste memory on large elements.
+///
+/// Slots are located with a modulo instead of a mask, and the indexes wrap
+/// around at twice the capacity instead of at `usize::MAX`, which costs a
+/// division and a few comparisons per operation. In the single-element
+/// workload of the `compare` benchmark, this makes it up to about a third
+/// slower than a [`RingBuffer`](crate::RingBuffer) of the same capacity, which
+/// thus stays the better choice whenever the capacity can be a power of two.
+///
+/// ```
+/// use ringbuffer_spsc::RingBufferAny;
+///
+/// let (mut tx, mut rx) = RingBufferAny::<usize>::init(3).unwrap();
+/// for i in 0..3 {
+///     assert!(tx.push(i).is_none());
+/// }
+/// assert_eq!(tx.push(3), Some(3));
+/// assert_eq!(rx.pull(), Some(0));
+/// ```
+pub struct RingBufferAny<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    idx_r: CachePadded<AtomicUsize>,
+    idx_w: CachePadded<AtomicUsize>,
+    // Set by whichever handle is dropped first.
+    disconnected: AtomicBool,
+}
+
+unsafe impl<T: Send> Send for RingBufferAny<T> {}
+unsafe impl<T: Send> Sync for RingBufferAny<T> {}
+
+impl<T> RingBufferAny<T> {
+    /// Creates a ring buffer of the given capacity, which must not be zero.
+    pub fn init(capacity: usize) -> Result<(AnyWriter<T>, AnyReader<T>), CapacityError> {
+        if capacity == 0 {
+            return Err(CapacityError::Zero);
+        }
+        // The indexes range over twice the capacity
+        if capacity > usize::MAX / 2 {
+            return Err(CapacityError::TooLarge);
+        }
+        let rb = Arc::new(RingBufferAny {
+            buffer: core::iter::repeat_with(|| UnsafeCell::new(MaybeUninit::uninit()))
+                .take(capacity)
+                .collect(),
+            idx_r: CachePadded::new(AtomicUsize::new(0)),
+            idx_w: CachePadded::new(AtomicUsize::new(0)),
+            disconnected: AtomicBool::new(false),
+        });
+        Ok((
+            AnyWriter {
+                inner: rb.clone(),
+                cached_idx_r: 0,
+                local_idx_w: 0,
+            },
+            AnyReader {
+                inner: rb,
+                local_idx_r: 0,
+                cached_idx_w: 0,
+            },
+        ))
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of elements from index `from` up to index `to`.
+    #[inline]
+    fn distance(&self, from: usize, to: usize) -> usize {
+        if to >= from {
+            to - from
+        } else {
+            to + 2 * self.capacity() - from
+        }
+    }
+
+    /// Returns the index following `idx`.
+    #[inline]
+    fn next(&self, idx: usize) -> usize {
+        let idx = idx + 1;
+        if idx == 2 * self.capacity() {
+            0
+        } else {
+            idx
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut(&self, idx: usize) -> &mut MaybeUninit<T> {
+        unsafe { &mut *self.buffer[idx % self.capacity()].get() }
+    }
+}
+
+impl<T> Drop for RingBufferAny<T> {
+    fn drop(&mut self) {
+        let mut idx_r = *self.idx_r.get_mut();
+        let idx_w = *self.idx_w.get_mut();
+
+        while idx_r != idx_w {
+            let t =
+                unsafe { mem::replace(self.get_mut(idx_r), MaybeUninit::uninit()).assume_init() };
+            mem::drop(t);
+            idx_r = self.next(idx_r);
+        }
+    }
+}
+
+/// The writer of a [`RingBufferAny`].
+pub struct AnyWriter<T> {
+    inner: Arc<RingBufferAny<T>>,
+    cached_idx_r: usize,
+    local_idx_w: usize,
+}
+
+impl<T> AnyWriter<T> {
+    /// Pushes an element, or gives it back if the ring buffer is full.
+    #[inline]
+    pub fn push(&mut self, t: T) -> Option<T> {
+        if self.is_full() {
+            return Some(t);
+        }
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.local_idx_w = self.inner.next(self.local_idx_w);
+        self.inner.idx_w.store(self.local_idx_w, Ordering::Release);
+
+        None
+    }
+
+    /// Returns `true` if the ring buffer is full.
+    #[inline]
+    pub fn is_full(&mut self) -> bool {
+        let capacity = self.inner.capacity();
+        if self.inner.distance(self.cached_idx_r, self.local_idx_w) == capacity {
+            self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+            self.inner.distance(self.cached_idx_r, self.local_idx_w) == capacity
+        } else {
+            false
+        }
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns `false` once the reader has been dropped, after which pushed
+    /// elements are never pulled.
+    pub fn is_reader_alive(&self) -> bool {
+        !self.inner.disconnected.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Drop for AnyWriter<T> {
+    fn drop(&mut self) {
+        self.inner.disconnected.store(true, Ordering::Release);
+    }
+}
+
+/// The reader of a [`RingBufferAny`].
+pub struct AnyReader<T> {
+    inner: Arc<RingBufferAny<T>>,
+    local_idx_r: usize,
+    cached_idx_w: usize,
+}
+
+impl<T> AnyReader<T> {
+    /// Pulls the oldest element, or returns `None` if the ring buffer is
+    /// empty.
+    #[inline]
+    pub fn pull(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let t = unsafe {
+            mem::replace(self.inner.get_mut(self.local_idx_r), MaybeUninit::uninit()).assume_init()
+        };
+        self.local_idx_r = self.inner.next(self.local_idx_r);
+        self.inner.idx_r.store(self.local_idx_r, Ordering::Release);
+
+        Some(t)
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    #[inline]
+    pub fn is_empty(&mut self) -> bool {
+        if self.local_idx_r == self.cached_idx_w {
+            self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+            self.local_idx_r == self.cached_idx_w
+        } else {
+            false
+        }
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns `false` once the writer has been dropped, after which no more
+    /// elements are pushed. Elements pushed before might still be available.
+    pub fn is_writer_alive(&self) -> bool {
+        !self.inner.disconnected.load(Ordering::Acquire)
+    }
+}
+
+impl<T> Drop for AnyReader<T> {
+    fn drop(&mut self) {
+        self.inner.disconnected.store(true, Ordering::Release);
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{
+    drop_range, forward, is_empty, is_full, slot, AtomicIndex, CachePadded, CapacityError, Index,
+};
+use core::{
+    cell::UnsafeCell,
+    mem::MaybeUninit,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A ring buffer storing its elements inline rather than in a heap
+/// allocation, for targets without an allocator.
+///
+/// The handles borrow the ring buffer instead of sharing it through an
+/// `Arc`, so it can be placed in a `static` and used e.g. from an interrupt
+/// handler:
+///
+/// ```
+/// use ringbuffer_spsc::RingBufferArray;
+///
+/// static RING: RingBufferArray<u8, 16> = RingBufferArray::new();
+///
+/// let (mut tx, mut rx) = RING.split().unwrap();
+/// assert!(tx.push(42).is_none());
+/// assert_eq!(rx.pull(), Some(42));
+/// ```
+///
+/// The capacity must be a power of two, which is checked at compile time:
+///
+/// ```compile_fail
+/// use ringbuffer_spsc::RingBufferArray;
+///
+/// static RING: RingBufferArray<u8, 12> = RingBufferArray::new();
+/// ```
+pub struct RingBufferArray<T, const N: usize> {
+    buffer: [UnsafeCell<MaybeUninit<T>>; N],
+    idx_r: CachePadded<AtomicIndex>,
+    idx_w: CachePadded<AtomicIndex>,
+    split: AtomicBool,
+}
+
+unsafe impl<T: Send, const N: usize> Send for RingBufferArray<T, N> {}
+unsafe impl<T: Send, const N: usize> Sync for RingBufferArray<T, N> {}
+
+impl<T, const N: usize> RingBufferArray<T, N> {
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                CapacityError::check(N).is_ok(),
+                "RingBufferArray capacity must be a power of two."
+            )
+        };
+        Self {
+            buffer: [const { UnsafeCell::new(MaybeUninit::uninit()) }; N],
+            idx_r: CachePadded::new(AtomicIndex::new(0)),
+            idx_w: CachePadded::new(AtomicIndex::new(0)),
+            split: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the writer and the reader of this ring buffer, or `None` if
+    /// they have already been handed out.
+    pub fn split(&self) -> Option<(ArrayWriter<'_, T, N>, ArrayReader<'_, T, N>)> {
+        if self.split.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        let idx_r = self.idx_r.load(Ordering::Acquire);
+        let idx_w = self.idx_w.load(Ordering::Acquire);
+        Some((
+            ArrayWriter {
+                inner: self,
+                cached_idx_r: idx_r,
+                local_idx_w: idx_w,
+            },
+            ArrayReader {
+                inner: self,
+                local_idx_r: idx_r,
+                cached_idx_w: idx_w,
+            },
+        ))
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    #[inline]
+    unsafe fn get_mut(&self, idx: Index) -> &mut MaybeUninit<T> {
+        unsafe { &mut *self.buffer[slot::<N>(idx)].get() }
+    }
+}
+
+impl<T, const N: usize> Default for RingBufferArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for RingBufferArray<T, N> {
+    fn drop(&mut self) {
+        let idx_r = *self.idx_r.get_mut();
+        let idx_w = *self.idx_w.get_mut();
+        unsafe { drop_range(&self.buffer, idx_r, idx_w) };
+    }
+}
+
+/// The writer of a [`RingBufferArray`].
+pub struct ArrayWriter<'a, T, const N: usize> {
+    inner: &'a RingBufferArray<T, N>,
+    cached_idx_r: Index,
+    local_idx_w: Index,
+}
+
+impl<T, const N: usize> ArrayWriter<'_, T, N> {
+    /// Pushes an element, or gives it back if the ring buffer is full.
+    #[inline]
+    pub fn push(&mut self, t: T) -> Option<T> {
+        if self.is_full() {
+            return Some(t);
+        }
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.local_idx_w = forward(self.local_idx_w, 1);
+        self.inner.idx_w.store(self.local_idx_w, Ordering::Release);
+
+        None
+    }
+
+    /// Returns `true` if the ring buffer is full.
+    #[inline]
+    pub fn is_full(&mut self) -> bool {
+        if is_full::<N>(self.cached_idx_r, self.local_idx_w) {
+            self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+            is_full::<N>(self.cached_idx_r, self.local_idx_w)
+        } else {
+            false
+        }
+    }
+}
+
+/// The reader of a [`RingBufferArray`].
+pub struct ArrayReader<'a, T, const N: usize> {
+    inner: &'a RingBufferArray<T, N>,
+    local_idx_r: Index,
+    cached_idx_w: Index,
+}
+
+impl<T, const N: usize> ArrayReader<'_, T, N> {
+    /// Pulls the oldest element, or returns `None` if the ring buffer is
+    /// empty.
+    #[inline]
+    pub fn pull(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let t = unsafe { self.inner.get_mut(self.local_idx_r).assume_init_read() };
+        self.local_idx_r = forward(self.local_idx_r, 1);
+        self.inner.idx_r.store(self.local_idx_r, Ordering::Release);
+
+        Some(t)
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    #[inline]
+    pub fn is_empty(&mut self) -> bool {
+        if is_empty(self.local_idx_r, self.cached_idx_w) {
+            self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+            is_empty(self.local_idx_r, self.cached_idx_w)
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,426 @@
+#[cfg(feature = "metrics")]
+use crate::Index;
+use crate::{distance, slot, RingBuffer, RingBufferReader, RingBufferWriter};
+use alloc::{sync::Arc, vec::Vec};
+use core::sync::atomic::{self, AtomicBool, Ordering};
+use std::{
+    sync::{Condvar, Mutex},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Exponential backoff for polling loops: spin first, then yield the thread
+/// and finally sleep for increasingly longer periods.
+pub(crate) struct Backoff {
+    step: u32,
+}
+
+impl Backoff {
+    const SPIN_LIMIT: u32 = 6;
+    const YIELD_LIMIT: u32 = 10;
+    const MAX_SLEEP: Duration = Duration::from_millis(1);
+
+    pub(crate) fn new() -> Self {
+        Self { step: 0 }
+    }
+
+    pub(crate) fn snooze(&mut self) {
+        if self.step <= Self::SPIN_LIMIT {
+            for _ in 0..1 << self.step {
+                core::hint::spin_loop();
+            }
+        } else if self.step <= Self::YIELD_LIMIT {
+            thread::yield_now();
+        } else {
+            let sleep = Duration::from_micros(1 << (self.step - Self::YIELD_LIMIT));
+            thread::sleep(sleep.min(Self::MAX_SLEEP));
+        }
+        self.step = self.step.saturating_add(1);
+    }
+}
+
+/// Moving average of the intervals between successful adaptive pulls, used to
+/// decide how long to spin before backing off.
+pub(crate) struct Arrivals {
+    last: Option<Instant>,
+    avg: Duration,
+}
+
+impl Arrivals {
+    const MAX_SPIN: Duration = Duration::from_micros(100);
+
+    pub(crate) fn new() -> Self {
+        Self {
+            last: None,
+            avg: Self::MAX_SPIN,
+        }
+    }
+
+    fn record(&mut self, now: Instant) {
+        if let Some(last) = self.last {
+            // Exponential moving average with a weight of 1/8 for the new sample
+            self.avg = (self.avg * 7 + now.duration_since(last)) / 8;
+        }
+        self.last = Some(now);
+    }
+
+    fn spin_budget(&self) -> Duration {
+        // Spin for a couple of expected intervals when arrivals are frequent,
+        // back off right away when they are sparse.
+        if self.avg <= Self::MAX_SPIN {
+            self.avg * 2
+        } else {
+            Duration::ZERO
+        }
+    }
+}
+
+/// Moving average of the time the reader takes to free a slot, sampled by
+/// the writer whenever it observes the read index advance in
+/// `push_with_hint()`, the only place calling `observe()`.
+#[cfg(feature = "metrics")]
+pub(crate) struct DrainRate {
+    last: Option<(Index, Instant)>,
+    per_slot: Duration,
+}
+
+#[cfg(feature = "metrics")]
+impl DrainRate {
+    pub(crate) fn new() -> Self {
+        Self {
+            last: None,
+            per_slot: Duration::ZERO,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, idx_r: Index) {
+        match self.last {
+            Some((last, _)) if last == idx_r => {}
+            Some((last, at)) => {
+                let now = Instant::now();
+                // More than u32::MAX freed slots only shortens the sample
+                let freed = u32::try_from(distance(last, idx_r)).unwrap_or(u32::MAX);
+                let sample = now.duration_since(at) / freed;
+                // Exponential moving average with a weight of 1/4 for the new sample
+                self.per_slot = if self.per_slot.is_zero() {
+                    sample
+                } else {
+                    (self.per_slot * 3 + sample) / 4
+                };
+                self.last = Some((idx_r, now));
+            }
+            None => self.last = Some((idx_r, Instant::now())),
+        }
+    }
+
+    pub(crate) fn per_slot(&self) -> Duration {
+        self.per_slot
+    }
+}
+
+struct Signal {
+    mutex: Mutex<()>,
+    condvar: Condvar,
+    // Whether the writer or the reader is about to wait on the condvar, so
+    // that the peer only takes the mutex to notify it when needed.
+    writer_waiting: AtomicBool,
+    reader_waiting: AtomicBool,
+}
+
+impl Signal {
+    /// Parks the calling thread until `op` succeeds or `deadline` expires,
+    /// with `waiting` set while it is parked.
+    fn wait<R>(
+        &self,
+        waiting: &AtomicBool,
+        deadline: Option<Instant>,
+        mut op: impl FnMut() -> Option<R>,
+    ) -> Option<R> {
+        let mut guard = self.mutex.lock().unwrap();
+        loop {
+            waiting.store(true, Ordering::Relaxed);
+            // Pairs with the fence in `notify()`: either the peer sees the
+            // flag set, or the retried operation sees the peer's progress.
+            atomic::fence(Ordering::SeqCst);
+            if let Some(r) = op() {
+                waiting.store(false, Ordering::Relaxed);
+                return Some(r);
+            }
+            // Wakeups may be spurious, so the deadline is checked against
+            // the clock rather than the result of the wait.
+            guard = match deadline {
+                None => self.condvar.wait(guard).unwrap(),
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        waiting.store(false, Ordering::Relaxed);
+                        return None;
+                    }
+                    self.condvar.wait_timeout(guard, deadline - now).unwrap().0
+                }
+            };
+        }
+    }
+
+    /// Wakes up the peer if it is waiting, after the calling thread made
+    /// progress.
+    fn notify(&self, waiting: &AtomicBool) {
+        atomic::fence(Ordering::SeqCst);
+        if waiting.load(Ordering::Relaxed) && waiting.swap(false, Ordering::Relaxed) {
+            // Taking the mutex ensures the peer is either parked already or
+            // hasn't retried its operation yet.
+            drop(self.mutex.lock().unwrap());
+            self.condvar.notify_one();
+        }
+    }
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a ring buffer whose handles block the calling thread on a
+    /// [`Condvar`] when the buffer is full or empty.
+    ///
+    /// The shared [`Mutex`] is only taken on the slow path, i.e. to wait when
+    /// the buffer is full or empty and to wake up a waiting peer. Operations
+    /// that neither wait nor find the peer waiting stay lock-free, at the cost
+    /// of a full memory fence.
+    pub fn init_blocking() -> (BlockingWriter<T, N>, BlockingReader<T, N>) {
+        let (tx, rx) = Self::init();
+        let signal = Arc::new(Signal {
+            mutex: Mutex::new(()),
+            condvar: Condvar::new(),
+            writer_waiting: AtomicBool::new(false),
+            reader_waiting: AtomicBool::new(false),
+        });
+        (
+            BlockingWriter {
+                inner: tx,
+                signal: signal.clone(),
+            },
+            BlockingReader { inner: rx, signal },
+        )
+    }
+}
+
+pub struct BlockingWriter<T, const N: usize> {
+    inner: RingBufferWriter<T, N>,
+    signal: Arc<Signal>,
+}
+
+impl<T, const N: usize> BlockingWriter<T, N> {
+    /// Pushes an element, parking the thread while the ring buffer is full.
+    pub fn push_blocking(&mut self, t: T) {
+        // Can't time out without a deadline
+        let _ = self.push_until(t, None);
+    }
+
+    /// Pushes an element, parking the thread for up to `dur` while the ring
+    /// buffer is full. The element is handed back if the timeout expires.
+    pub fn push_timeout(&mut self, t: T, dur: Duration) -> Result<(), T> {
+        self.push_until(t, Instant::now().checked_add(dur))
+    }
+
+    fn push_until(&mut self, t: T, deadline: Option<Instant>) -> Result<(), T> {
+        let mut t = self.inner.push(t);
+        if t.is_some() {
+            let inner = &mut self.inner;
+            let pushed = self.signal.wait(&self.signal.writer_waiting, deadline, || {
+                t = inner.push(t.take().unwrap());
+                t.is_none().then_some(())
+            });
+            if pushed.is_none() {
+                return Err(t.unwrap());
+            }
+        }
+        self.signal.notify(&self.signal.reader_waiting);
+        Ok(())
+    }
+
+    /// Pushes an element, blocking while the ring buffer is full.
+    ///
+    /// This is the same as [`push_blocking`](Self::push_blocking).
+    pub fn send(&mut self, t: T) {
+        self.push_blocking(t)
+    }
+}
+
+pub struct BlockingReader<T, const N: usize> {
+    inner: RingBufferReader<T, N>,
+    signal: Arc<Signal>,
+}
+
+impl<T, const N: usize> BlockingReader<T, N> {
+    /// Pulls an element, parking the thread while the ring buffer is empty.
+    pub fn pull_blocking(&mut self) -> T {
+        // Can't time out without a deadline
+        self.pull_until(None).unwrap()
+    }
+
+    /// Pulls an element, parking the thread for up to `dur` while the ring
+    /// buffer is empty. Returns `None` if the timeout expires.
+    pub fn pull_timeout(&mut self, dur: Duration) -> Option<T> {
+        self.pull_until(Instant::now().checked_add(dur))
+    }
+
+    fn pull_until(&mut self, deadline: Option<Instant>) -> Option<T> {
+        let t = match self.inner.pull() {
+            Some(t) => t,
+            None => {
+                let inner = &mut self.inner;
+                self.signal
+                    .wait(&self.signal.reader_waiting, deadline, || inner.pull())?
+            }
+        };
+        self.signal.notify(&self.signal.writer_waiting);
+        Some(t)
+    }
+
+    /// Parks the thread while the ring buffer is empty, then moves up to
+    /// `max` available elements into `out` in FIFO order without parking
+    /// again, and returns how many were moved. This amortizes the cost of
+    /// waking up over a burst of elements.
+    ///
+    /// With a `max` of 0, this still parks until an element is available, but
+    /// moves nothing and returns 0.
+    pub fn pull_batch_blocking(&mut self, out: &mut Vec<T>, max: usize) -> usize {
+        if self.inner.is_empty() {
+            let inner = &mut self.inner;
+            self.signal.wait(&self.signal.reader_waiting, None, || {
+                (!inner.is_empty()).then_some(())
+            });
+        }
+        let len = out.len();
+        out.extend(self.inner.drain().take(max));
+        let n = out.len() - len;
+        if n > 0 {
+            self.signal.notify(&self.signal.writer_waiting);
+        }
+        n
+    }
+
+    /// Pulls an element, blocking while the ring buffer is empty.
+    ///
+    /// This is the same as [`pull_blocking`](Self::pull_blocking).
+    pub fn recv(&mut self) -> T {
+        self.pull_blocking()
+    }
+
+    /// Pulls a batch of elements, blocking while the ring buffer is empty.
+    ///
+    /// This is the same as [`pull_batch_blocking`](Self::pull_batch_blocking).
+    pub fn recv_batch(&mut self, out: &mut Vec<T>, max: usize) -> usize {
+        self.pull_batch_blocking(out, max)
+    }
+}
+
+impl<T, const N: usize> RingBufferWriter<T, N> {
+    /// Pushes an element like [`push`](Self::push), or gives it back along
+    /// with a suggested delay before retrying if the buffer is full.
+    ///
+    /// The delay is a moving average of the time the reader recently took to
+    /// free a slot, sampled whenever this method observes the read index
+    /// advance. It is zero until the reader has been observed to progress.
+    ///
+    /// Only this method samples the rate: pushes made with any other method
+    /// are not observed, so that mixing them makes a sample span every slot
+    /// freed since the previous call to this method.
+    #[cfg(feature = "metrics")]
+    pub fn push_with_hint(&mut self, t: T) -> Result<(), (T, Duration)> {
+        let res = self.push(t);
+        self.drain_rate.observe(self.cached_idx_r);
+        match res {
+            None => Ok(()),
+            Some(t) => Err((t, self.drain_rate.per_slot())),
+        }
+    }
+
+    /// Pushes an element, waiting with backoff while the ring buffer is full,
+    /// and gives the element back once `shutdown` is set.
+    ///
+    /// Like [`RingBufferReader::pull_until`], `shutdown` is checked on every
+    /// backoff step, so that a producer waiting for a consumer that is gone
+    /// notices a shutdown request promptly.
+    pub fn push_until(&mut self, mut t: T, shutdown: &AtomicBool) -> Option<T> {
+        let mut backoff = Backoff::new();
+        loop {
+            t = self.push(t)?;
+            if shutdown.load(Ordering::Acquire) {
+                return Some(t);
+            }
+            backoff.snooze();
+        }
+    }
+}
+
+impl<T, const N: usize> RingBufferReader<T, N> {
+    /// Pulls an element, waiting while the ring buffer is empty.
+    ///
+    /// The reader keeps a moving average of the intervals between successful
+    /// calls: while elements arrive frequently it busy-spins for about twice
+    /// that interval to minimize latency, while it backs off (yielding and then
+    /// sleeping) right away when arrivals are sparse to save CPU.
+    pub fn pull_adaptive(&mut self) -> T {
+        let spin = self.arrivals.spin_budget();
+        let start = Instant::now();
+        let mut backoff = Backoff::new();
+        let t = loop {
+            if let Some(t) = self.pull() {
+                break t;
+            }
+            if start.elapsed() < spin {
+                core::hint::spin_loop();
+            } else {
+                backoff.snooze();
+            }
+        };
+        self.arrivals.record(Instant::now());
+        t
+    }
+
+    /// Pulls an element, waiting with backoff while the ring buffer is empty,
+    /// and returns `None` once `shutdown` is set.
+    ///
+    /// `shutdown` is checked on every backoff step, which sleeps for at most
+    /// a millisecond, so that a consumer waiting for elements that will never
+    /// come notices a shutdown request promptly.
+    pub fn pull_until(&mut self, shutdown: &AtomicBool) -> Option<T> {
+        let mut backoff = Backoff::new();
+        loop {
+            if let Some(t) = self.pull() {
+                return Some(t);
+            }
+            if shutdown.load(Ordering::Acquire) {
+                return None;
+            }
+            backoff.snooze();
+        }
+    }
+
+    /// Waits up to `timeout` for at least `min` elements to be available
+    /// contiguously, i.e. without wrapping around the end of the buffer, and
+    /// returns them as a slice without consuming them.
+    ///
+    /// The contiguous run starting at the read index can't extend past the end
+    /// of the buffer: once the available elements reach it, the run is returned
+    /// right away even if shorter than `min`, since waiting longer would not
+    /// make it grow. A returned slice shorter than `min` thus signals that the
+    /// elements up to the wrap need to be consumed first.
+    ///
+    /// Returns `None` if the timeout expires.
+    pub fn wait_contiguous(&mut self, min: usize, timeout: Duration) -> Option<&[T]> {
+        let deadline = Instant::now() + timeout;
+        let start = slot::<N>(self.local_idx_r);
+        let mut backoff = Backoff::new();
+        loop {
+            self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+            let len = distance(self.local_idx_r, self.cached_idx_w);
+            if len >= min.min(N - start) {
+                break;
+            }
+            if Instant::now() >= deadline {
+                return None;
+            }
+            backoff.snooze();
+        }
+        Some(self.as_slices().0)
+    }
+}
@@ -0,0 +1,43 @@
+use crate::RingBufferWriter;
+use alloc::vec::Vec;
+
+/// A writer pushing clones of each element into several ring buffers, e.g.
+/// to feed independent consumers with the same data.
+pub struct BroadcastWriter<T, const N: usize> {
+    writers: Vec<RingBufferWriter<T, N>>,
+}
+
+impl<T: Clone, const N: usize> BroadcastWriter<T, N> {
+    pub fn new(writers: Vec<RingBufferWriter<T, N>>) -> Self {
+        Self { writers }
+    }
+
+    /// Pushes a clone of `t` into every ring buffer, honoring the
+    /// [`FullPolicy`](crate::FullPolicy) of each writer.
+    ///
+    /// Returns the indexes of the writers that rejected the element, if any.
+    /// The element is not removed from the ring buffers that accepted it.
+    pub fn push(&mut self, t: T) -> Result<(), Vec<usize>> {
+        let mut rejected = Vec::new();
+        if let Some((last, writers)) = self.writers.split_last_mut() {
+            for (i, w) in writers.iter_mut().enumerate() {
+                if w.push(t.clone()).is_some() {
+                    rejected.push(i);
+                }
+            }
+            if last.push(t).is_some() {
+                rejected.push(writers.len());
+            }
+        }
+        if rejected.is_empty() {
+            Ok(())
+        } else {
+            Err(rejected)
+        }
+    }
+
+    /// Returns the underlying writers.
+    pub fn into_inner(self) -> Vec<RingBufferWriter<T, N>> {
+        self.writers
+    }
+}
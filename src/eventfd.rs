@@ -0,0 +1,108 @@
+use crate::{RingBuffer, RingBufferReader, RingBufferWriter};
+use alloc::sync::Arc;
+use core::sync::atomic::{self, Ordering};
+use std::{
+    io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd},
+};
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a ring buffer paired with an `eventfd(2)` that becomes readable
+    /// when an element is pushed into the empty buffer, so that the consumer
+    /// can be driven by an `epoll`-based event loop.
+    ///
+    /// The file descriptor is exposed by the [`EventFdReader`], whose
+    /// [`pull`](EventFdReader::pull) resets it once the buffer is drained. The
+    /// consumer is thus expected to pull until `None` every time the file
+    /// descriptor is reported readable.
+    pub fn init_eventfd() -> io::Result<(EventFdWriter<T, N>, EventFdReader<T, N>)> {
+        let fd = unsafe { libc::eventfd(0, libc::EFD_NONBLOCK | libc::EFD_CLOEXEC) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let fd = Arc::new(unsafe { OwnedFd::from_raw_fd(fd) });
+
+        let (tx, rx) = Self::init();
+        Ok((
+            EventFdWriter {
+                inner: tx,
+                fd: fd.clone(),
+            },
+            EventFdReader { inner: rx, fd },
+        ))
+    }
+}
+
+pub struct EventFdWriter<T, const N: usize> {
+    inner: RingBufferWriter<T, N>,
+    fd: Arc<OwnedFd>,
+}
+
+impl<T, const N: usize> EventFdWriter<T, N> {
+    /// Pushes an element like [`RingBufferWriter::push`], and signals the
+    /// eventfd if the buffer was empty.
+    pub fn push(&mut self, t: T) -> Option<T> {
+        let t = self.inner.push(t);
+        if t.is_none() {
+            // Pairs with the fence in `EventFdReader::pull()`: either the
+            // reader sees the new element, or the writer sees the buffer empty.
+            atomic::fence(Ordering::SeqCst);
+            let idx_r = self.inner.inner.idx_r.load(Ordering::Acquire);
+            if idx_r.wrapping_add(1) == self.inner.local_idx_w {
+                let one = 1u64;
+                // The write can only fail if the counter overflows, in which
+                // case the eventfd is readable anyway.
+                unsafe {
+                    libc::write(
+                        self.fd.as_raw_fd(),
+                        &one as *const u64 as *const libc::c_void,
+                        8,
+                    )
+                };
+            }
+        }
+        t
+    }
+}
+
+pub struct EventFdReader<T, const N: usize> {
+    inner: RingBufferReader<T, N>,
+    fd: Arc<OwnedFd>,
+}
+
+impl<T, const N: usize> EventFdReader<T, N> {
+    /// Pulls an element like [`RingBufferReader::pull`]. If the buffer is
+    /// empty, the eventfd is reset so that it becomes readable again on the
+    /// next push.
+    pub fn pull(&mut self) -> Option<T> {
+        if let Some(t) = self.inner.pull() {
+            return Some(t);
+        }
+        let mut count = 0u64;
+        // The read fails with EAGAIN if the eventfd is not readable, i.e. it
+        // is already reset.
+        unsafe {
+            libc::read(
+                self.fd.as_raw_fd(),
+                &mut count as *mut u64 as *mut libc::c_void,
+                8,
+            )
+        };
+        // An element pushed before the reset might not have signaled the
+        // eventfd, so check again.
+        atomic::fence(Ordering::SeqCst);
+        self.inner.pull()
+    }
+}
+
+impl<T, const N: usize> AsFd for EventFdReader<T, N> {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl<T, const N: usize> AsRawFd for EventFdReader<T, N> {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
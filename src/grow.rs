@@ -0,0 +1,162 @@
+use crate::{AnyReader, AnyWriter, CapacityError, RingBuffer, RingBufferAny};
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    mem, ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+/// The reader of the next segment, published by the writer once it has
+/// stopped pushing into the current one.
+pub(crate) struct Handoff<T> {
+    next: AtomicPtr<Segment<T>>,
+}
+
+impl<T> Drop for Handoff<T> {
+    fn drop(&mut self) {
+        // Unlink the segments one at a time rather than recursively, so that
+        // dropping a long chain doesn't overflow the stack
+        let mut next = mem::replace(self.next.get_mut(), ptr::null_mut());
+        while !next.is_null() {
+            let Segment { rx, mut handoff } = *unsafe { Box::from_raw(next) };
+            drop(rx);
+            // The writer still holds the handoff of the segment it pushes into
+            next = match Arc::get_mut(&mut handoff) {
+                Some(handoff) => mem::replace(handoff.next.get_mut(), ptr::null_mut()),
+                None => ptr::null_mut(),
+            };
+        }
+    }
+}
+
+impl<T> Handoff<T> {
+    /// Publishes `segment` as the next one, after which the writer must not
+    /// push into the current one anymore.
+    pub(crate) fn publish(&self, segment: Segment<T>) {
+        self.next
+            .store(Box::into_raw(Box::new(segment)), Ordering::Release);
+    }
+}
+
+/// A ring buffer of the chain along with the handoff of its successor.
+pub(crate) struct Segment<T> {
+    rx: AnyReader<T>,
+    pub(crate) handoff: Arc<Handoff<T>>,
+}
+
+impl<T> Segment<T> {
+    /// Pulls an element, switching to the next segment once this one has
+    /// been drained.
+    pub(crate) fn pull(&mut self) -> Option<T> {
+        loop {
+            if let Some(t) = self.rx.pull() {
+                return Some(t);
+            }
+            let next = self.handoff.next.load(Ordering::Acquire);
+            if next.is_null() {
+                return None;
+            }
+            // The writer might have pushed more elements before moving on
+            if let Some(t) = self.rx.pull() {
+                return Some(t);
+            }
+            // The writer is done with the handoff, take over the next segment
+            self.handoff.next.store(ptr::null_mut(), Ordering::Relaxed);
+            let next = unsafe { Box::from_raw(next) };
+            *self = *next;
+        }
+    }
+
+    /// Returns the capacity of the ring buffer of this segment.
+    pub(crate) fn capacity(&self) -> usize {
+        self.rx.capacity()
+    }
+}
+
+pub(crate) fn segment<T>(capacity: usize) -> Result<(AnyWriter<T>, Segment<T>), CapacityError> {
+    let (tx, rx) = RingBufferAny::init(capacity)?;
+    let handoff = Arc::new(Handoff {
+        next: AtomicPtr::new(ptr::null_mut()),
+    });
+    Ok((tx, Segment { rx, handoff }))
+}
+
+fn segment_or_panic<T>(capacity: usize) -> (AnyWriter<T>, Segment<T>) {
+    match segment(capacity) {
+        Ok(handles) => handles,
+        Err(e) => panic!("RingBuffer capacity {capacity} is invalid: {e}."),
+    }
+}
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a ring buffer of initial capacity `N` whose writer doubles its
+    /// capacity instead of rejecting elements when full.
+    ///
+    /// Growing the storage in place is unsafe while the reader reads it, so
+    /// the writer allocates a new ring buffer of twice the capacity, moves on
+    /// to it and hands its reader over to the reader, which switches to it
+    /// once it has drained the full one. The elements of the full ring buffer
+    /// are thus migrated by the reader pulling them, without being copied,
+    /// and are always pulled before the ones pushed after growing.
+    ///
+    /// The synchronization cost is an extra atomic load for the reader
+    /// whenever its current ring buffer is empty, and an allocation for the
+    /// writer every time it grows, i.e. a number of times logarithmic in the
+    /// largest backlog. Since the capacity is no longer known at compile
+    /// time, the ring buffers are [`RingBufferAny`]s, whose slots are located
+    /// with a modulo. Drained ring buffers are freed by the reader.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero, or when growing if the capacity would exceed
+    /// `usize::MAX / 2`.
+    pub fn init_growing() -> (GrowingWriter<T>, GrowingReader<T>) {
+        let (tx, segment) = segment_or_panic(N);
+        (
+            GrowingWriter {
+                tx,
+                handoff: segment.handoff.clone(),
+            },
+            GrowingReader { segment },
+        )
+    }
+}
+
+pub struct GrowingWriter<T> {
+    tx: AnyWriter<T>,
+    handoff: Arc<Handoff<T>>,
+}
+
+impl<T> GrowingWriter<T> {
+    /// Pushes an element, doubling the capacity if the buffer is full.
+    pub fn push(&mut self, t: T) {
+        if let Some(t) = self.tx.push(t) {
+            let (mut tx, segment) = segment_or_panic(2 * self.tx.capacity());
+            assert!(tx.push(t).is_none());
+            let handoff = segment.handoff.clone();
+            // No element is pushed into the full ring buffer after this point
+            self.handoff.publish(segment);
+            (self.tx, self.handoff) = (tx, handoff);
+        }
+    }
+
+    /// Returns the capacity of the ring buffer currently pushed into.
+    pub fn capacity(&self) -> usize {
+        self.tx.capacity()
+    }
+}
+
+pub struct GrowingReader<T> {
+    segment: Segment<T>,
+}
+
+impl<T> GrowingReader<T> {
+    /// Pulls an element, switching to the next ring buffer once the current
+    /// one has been drained.
+    pub fn pull(&mut self) -> Option<T> {
+        self.segment.pull()
+    }
+
+    /// Returns the capacity of the ring buffer currently pulled from.
+    pub fn capacity(&self) -> usize {
+        self.segment.capacity()
+    }
+}
@@ -38,25 +38,189 @@
 //! ```
 #![no_std]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
-use alloc::sync::Arc;
-use cache_padded::CachePadded;
+mod any;
+pub use any::{AnyReader, AnyWriter, RingBufferAny};
+mod array;
+pub use array::{ArrayReader, ArrayWriter, RingBufferArray};
+mod broadcast;
+pub use broadcast::BroadcastWriter;
+mod grow;
+pub use grow::{GrowingReader, GrowingWriter};
+mod local;
+pub use local::LocalRingBuffer;
+mod migrate;
+pub use migrate::{MigratingReader, MigratingWriter, Migration};
+mod recycle;
+pub use recycle::{RecyclingConsumer, RecyclingProducer};
+mod select;
+pub use select::Selector;
+#[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+mod owner;
+#[cfg(feature = "replay-log")]
+mod replay;
+#[cfg(feature = "replay-log")]
+pub use replay::{ReplayEvent, ReplayOp};
+
+#[cfg(feature = "std")]
+mod blocking;
+#[cfg(feature = "std")]
+pub use blocking::{BlockingReader, BlockingWriter};
+#[cfg(all(feature = "eventfd", target_os = "linux"))]
+mod eventfd;
+#[cfg(feature = "async")]
+mod sink;
+#[cfg(all(feature = "eventfd", target_os = "linux"))]
+pub use eventfd::{EventFdReader, EventFdWriter};
+#[cfg(all(feature = "numa", target_os = "linux"))]
+mod numa;
+mod storage;
+use storage::Storage;
+
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use core::{
-    cell::UnsafeCell,
+    cell::{Cell, UnsafeCell},
+    fmt, iter,
+    marker::PhantomData,
     mem::{self, MaybeUninit},
-    sync::atomic::{AtomicUsize, Ordering},
+    ptr, slice,
+    sync::atomic::{self, AtomicBool, AtomicU8, AtomicUsize, Ordering},
 };
+use crossbeam_utils::CachePadded;
+
+// The monotonic indexes wrap around at `Index::MAX`. With the `u32-indices`
+// feature they take half the space on 64-bit targets, at the cost of limiting
+// the capacity to 2^31.
+#[cfg(not(feature = "u32-indices"))]
+type Index = usize;
+#[cfg(not(feature = "u32-indices"))]
+type AtomicIndex = core::sync::atomic::AtomicUsize;
+#[cfg(feature = "u32-indices")]
+type Index = u32;
+#[cfg(feature = "u32-indices")]
+type AtomicIndex = core::sync::atomic::AtomicU32;
+
+/// Returns the number of elements from index `from` up to index `to`,
+/// accounting for the wrap around.
+#[allow(clippy::unnecessary_cast)]
+#[inline]
+fn distance(from: Index, to: Index) -> usize {
+    to.wrapping_sub(from) as usize
+}
+
+/// Returns the index `n` elements after `idx`.
+#[allow(clippy::unnecessary_cast)]
+#[inline]
+fn forward(idx: Index, n: usize) -> Index {
+    idx.wrapping_add(n as Index)
+}
+
+/// Returns `true` if a ring buffer of capacity `N` is full, given its read
+/// index `idx_r` and its write index `idx_w`.
+#[inline]
+fn is_full<const N: usize>(idx_r: Index, idx_w: Index) -> bool {
+    distance(idx_r, idx_w) == N
+}
+
+/// Returns `true` if a ring buffer is empty, given its read index `idx_r` and
+/// its write index `idx_w`.
+#[inline]
+fn is_empty(idx_r: Index, idx_w: Index) -> bool {
+    idx_r == idx_w
+}
+
+/// Returns the slot of index `idx` in a storage of capacity `N`.
+#[inline]
+fn slot<const N: usize>(idx: Index) -> usize {
+    wrap(idx, N)
+}
+
+/// Returns the slot of index `idx` in a storage of power-of-two `capacity`.
+#[allow(clippy::unnecessary_cast)]
+#[inline]
+fn wrap(idx: Index, capacity: usize) -> usize {
+    // Since the capacity is a power of two, capacity-1 is a mask covering
+    // the slots.
+    idx as usize & (capacity - 1)
+}
+
+/// Drops the elements from index `idx_r` up to index `idx_w` in `slots`,
+/// whose length must be a power of two.
+///
+/// # Safety
+/// The elements must be initialized and not accessed concurrently.
+unsafe fn drop_range<T>(slots: &[UnsafeCell<MaybeUninit<T>>], mut idx_r: Index, idx_w: Index) {
+    while idx_r != idx_w {
+        unsafe { (*slots[wrap(idx_r, slots.len())].get()).assume_init_drop() };
+        idx_r = forward(idx_r, 1);
+    }
+}
 
 pub struct RingBuffer<T, const N: usize> {
-    buffer: UnsafeCell<[MaybeUninit<T>; N]>,
-    idx_r: CachePadded<AtomicUsize>,
-    idx_w: CachePadded<AtomicUsize>,
+    // The outer cell allows swapping the storage when both handles are
+    // exclusively borrowed, see `swap_storage()`.
+    buffer: UnsafeCell<Storage<T, N>>,
+    idx_r: CachePadded<AtomicIndex>,
+    idx_w: CachePadded<AtomicIndex>,
+    // Only set for buffers created with `init_lossy()`, where the writer may
+    // evict the oldest element and thus needs to exclude the reader.
+    lossy: bool,
+    lock: AtomicBool,
+    // Hint set by the reader, see `RingBufferReader::signal_backpressure()`.
+    backpressure: AtomicU8,
+    // Set by the first of the writer and the reader to be dropped.
+    disconnected: AtomicBool,
+    // The threads operating the handles, see `owner::Owner`.
+    #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+    writer_owner: owner::Owner,
+    #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+    reader_owner: owner::Owner,
+    // See `RingBufferWriter::poll_ready()`.
+    #[cfg(feature = "async")]
+    writer_waker: sink::WriterWaker,
+    // Only accessed by the writer, see `RingBufferWriter::scratch_mut()`.
+    scratch: UnsafeCell<MaybeUninit<T>>,
+    // The time each slot was last pushed to, see `RingBufferReader::pull_timed()`.
+    #[cfg(feature = "timestamps")]
+    stamps: Box<[UnsafeCell<std::time::Instant>]>,
+    #[cfg(feature = "metrics")]
+    metrics: Metrics,
 }
 
 unsafe impl<T, const N: usize> Send for RingBuffer<T, N> {}
 unsafe impl<T, const N: usize> Sync for RingBuffer<T, N> {}
 
 impl<T, const N: usize> RingBuffer<T, N> {
+    /// The base-2 logarithm of the capacity, i.e. the shift converting a
+    /// number of laps around the buffer into a monotonic index.
+    pub const CAPACITY_LOG2: u32 = N.trailing_zeros();
+
+    /// The size in bytes of the storage of the elements.
+    pub const CAPACITY_BYTES: usize = N * mem::size_of::<T>();
+
+    /// An approximation of the size in bytes allocated besides the storage of
+    /// the elements: the shared state of the handles, its cache-line padding
+    /// included, the two reference counts of the `Arc` holding it, and the
+    /// per-slot stamps with the `timestamps` feature.
+    ///
+    /// The layout of the `Arc` allocation is not specified, so any padding
+    /// between the counts and the shared state, which depends on the
+    /// alignment of the latter, is not accounted for, nor are heap
+    /// allocations made on demand, e.g. by callbacks.
+    pub const OVERHEAD_BYTES: usize =
+        2 * mem::size_of::<AtomicUsize>() + mem::size_of::<RingBuffer<T, N>>() + {
+            #[cfg(feature = "timestamps")]
+            {
+                N * mem::size_of::<std::time::Instant>()
+            }
+            #[cfg(not(feature = "timestamps"))]
+            {
+                0
+            }
+        };
+
     #[allow(clippy::new_ret_no_self)]
     #[deprecated(since = "0.1.8", note = "please use `init()` instead.")]
     pub fn new() -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
@@ -64,103 +228,1757 @@ impl<T, const N: usize> RingBuffer<T, N> {
     }
 
     pub fn init() -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
-        assert!(
-            N.is_power_of_two(),
-            "RingBuffer requires the capacity to be a power of 2. {N} is not."
-        );
-        let rb = Arc::new(RingBuffer {
-            buffer: UnsafeCell::new(array_init::array_init(|_| MaybeUninit::uninit())),
-            idx_r: CachePadded::new(AtomicUsize::new(0)),
-            idx_w: CachePadded::new(AtomicUsize::new(0)),
-        });
+        Self::init_from_box(Self::storage())
+    }
+
+    /// Creates a ring buffer like [`init`](Self::init), but returns an error
+    /// instead of panicking if `N` is not a valid capacity.
+    pub fn try_init() -> Result<(RingBufferWriter<T, N>, RingBufferReader<T, N>), CapacityError> {
+        CapacityError::check(N)?;
+        Ok(Self::init())
+    }
+
+    /// Creates a ring buffer backed by the provided storage instead of
+    /// allocating its own, e.g. to control the placement of the allocation.
+    ///
+    /// Any initialized value in `storage` is ignored and never dropped. The
+    /// storage is freed as usual once both handles have been dropped.
+    ///
+    /// # Panics
+    /// Panics if the length of `storage` is not `N`.
+    pub fn init_from_box(
+        storage: Box<[MaybeUninit<T>]>,
+    ) -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
+        let rb = Self::alloc(storage, false);
+        (RingBufferWriter::new(rb.clone()), RingBufferReader::new(rb))
+    }
+
+    /// Creates a ring buffer preloaded with the elements of `items`, e.g. to
+    /// warm start a consumer, which can pull them right away.
+    ///
+    /// At most `N` elements are taken from `items`, and the iterator is not
+    /// advanced any further: pass `iter.by_ref()` to keep the excess.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let mut items = 0..6;
+    /// let (_tx, mut rx) = RingBuffer::<usize, 4>::init_filled(items.by_ref());
+    /// assert_eq!(items.next(), Some(4));
+    /// assert_eq!(rx.pull(), Some(0));
+    /// ```
+    pub fn init_filled<I: IntoIterator<Item = T>>(
+        items: I,
+    ) -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
+        let mut storage = Self::storage();
+        let mut n = 0;
+        // The storage is zipped first so that no element is taken from
+        // `items` once it is full.
+        for (slot, t) in storage.iter_mut().zip(items) {
+            slot.write(t);
+            n += 1;
+        }
+        let (mut tx, rx) = Self::init_from_box(storage);
+        tx.local_idx_w = forward(0, n);
+        tx.inner.idx_w.store(tx.local_idx_w, Ordering::Release);
+        (tx, rx)
+    }
+
+    /// Creates a ring buffer whose writer can be configured with
+    /// [`FullPolicy::DropOldest`].
+    ///
+    /// Evicting the oldest element from the writer side requires the reader to
+    /// synchronize with the writer on every pull. The returned
+    /// [`RingBufferLossyReader`] does so with a short-lived spin lock, trading
+    /// some throughput for the ability to overwrite old elements.
+    pub fn init_lossy() -> (RingBufferWriter<T, N>, RingBufferLossyReader<T, N>) {
+        let rb = Self::alloc(Self::storage(), true);
         (
-            RingBufferWriter {
-                inner: rb.clone(),
-                cached_idx_r: 0,
-                local_idx_w: 0,
-            },
-            RingBufferReader {
-                inner: rb,
-                local_idx_r: 0,
-                cached_idx_w: 0,
-            },
+            RingBufferWriter::new(rb.clone()),
+            RingBufferLossyReader { inner: rb },
+        )
+    }
+
+    /// Creates a ring buffer whose writer always overwrites the oldest element
+    /// when full, like a writer of [`init_lossy`](Self::init_lossy)
+    /// configured with [`FullPolicy::DropOldest`] but without any runtime
+    /// policy check on the hot path.
+    pub fn init_overwrite() -> (
+        RingBufferWriter<T, N, Overwrite>,
+        RingBufferLossyReader<T, N>,
+    ) {
+        let rb = Self::alloc(Self::storage(), true);
+        (
+            RingBufferWriter::new(rb.clone()),
+            RingBufferLossyReader { inner: rb },
+        )
+    }
+
+    /// Creates a ring buffer whose elements are each padded to their own cache
+    /// line, so that the writer and the reader don't contend on the same line
+    /// when operating on neighbouring slots.
+    ///
+    /// This trades memory for less false sharing in small buffers under heavy
+    /// contention. The padding is transparent to the handles, which push and
+    /// pull plain `T`s.
+    pub fn init_padded() -> (PaddedWriter<T, N>, PaddedReader<T, N>) {
+        let (tx, rx) = RingBuffer::<Padded<T>, N>::init();
+        (
+            tx.with(Padded::new as fn(T) -> Padded<T>),
+            rx.map(Padded::into_inner as fn(Padded<T>) -> T),
         )
     }
 
+    /// Creates a ring buffer whose indexes start at `idx` instead of 0.
+    #[cfg(test)]
+    fn init_at(idx: Index) -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
+        let (mut tx, mut rx) = Self::init();
+        tx.inner.idx_r.store(idx, Ordering::Relaxed);
+        tx.inner.idx_w.store(idx, Ordering::Relaxed);
+        (tx.cached_idx_r, tx.local_idx_w) = (idx, idx);
+        (rx.local_idx_r, rx.cached_idx_w) = (idx, idx);
+        (tx, rx)
+    }
+
+    fn storage() -> Box<[MaybeUninit<T>]> {
+        core::iter::repeat_with(MaybeUninit::uninit)
+            .take(N)
+            .collect()
+    }
+
+    fn into_buffer(storage: Box<[MaybeUninit<T>]>) -> Box<[UnsafeCell<MaybeUninit<T>>; N]> {
+        if let Err(e) = CapacityError::check(N) {
+            panic!("RingBuffer capacity {N} is invalid: {e}.");
+        }
+        assert_eq!(
+            storage.len(),
+            N,
+            "RingBuffer requires a storage of length {N}."
+        );
+        // UnsafeCell<MaybeUninit<T>> has the same in-memory representation as
+        // MaybeUninit<T> and the length has been checked above.
+        unsafe { Box::from_raw(Box::into_raw(storage) as *mut _) }
+    }
+
+    fn alloc(storage: Box<[MaybeUninit<T>]>, lossy: bool) -> Arc<Self> {
+        Self::alloc_in(Storage::from_box(Self::into_buffer(storage)), lossy)
+    }
+
+    fn alloc_in(storage: Storage<T, N>, lossy: bool) -> Arc<Self> {
+        Arc::new(RingBuffer {
+            buffer: UnsafeCell::new(storage),
+            idx_r: CachePadded::new(AtomicIndex::new(0)),
+            idx_w: CachePadded::new(AtomicIndex::new(0)),
+            lossy,
+            lock: AtomicBool::new(false),
+            backpressure: AtomicU8::new(0),
+            disconnected: AtomicBool::new(false),
+            #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+            writer_owner: owner::Owner::new(),
+            #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+            reader_owner: owner::Owner::new(),
+            #[cfg(feature = "async")]
+            writer_waker: sink::WriterWaker::new(),
+            scratch: UnsafeCell::new(MaybeUninit::uninit()),
+            #[cfg(feature = "timestamps")]
+            stamps: iter::repeat_with(|| UnsafeCell::new(std::time::Instant::now()))
+                .take(N)
+                .collect(),
+            #[cfg(feature = "metrics")]
+            metrics: Metrics::default(),
+        })
+    }
+
+    #[inline]
+    fn lock(&self) {
+        while self
+            .lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+    }
+
+    #[inline]
+    fn unlock(&self) {
+        self.lock.store(false, Ordering::Release);
+    }
+
+    #[inline]
+    unsafe fn get(&self, idx: Index) -> &MaybeUninit<T> {
+        &*self.slots()[slot::<N>(idx)].get()
+    }
+
     #[allow(clippy::mut_from_ref)]
     #[inline]
-    unsafe fn get_mut(&self, idx: usize) -> &mut MaybeUninit<T> {
+    unsafe fn get_mut(&self, idx: Index) -> &mut MaybeUninit<T> {
         // Since N is a power of two, N-1 is a mask covering N
         // elements overflowing when N elements have been added.
         // Indexes are left growing indefinetely and naturally wraps
-        // around once the index increment reaches usize::MAX.
-        &mut (*self.buffer.get())[idx & (N - 1)]
+        // around once the index increment reaches Index::MAX.
+        &mut *self.slots()[slot::<N>(idx)].get()
+    }
+
+    #[inline]
+    fn slots(&self) -> &[UnsafeCell<MaybeUninit<T>>; N] {
+        // The storage is only replaced while both handles are exclusively borrowed.
+        unsafe { &*self.buffer.get() }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *mut T {
+        self.slots().as_ptr() as *mut T
+    }
+
+    /// Replaces the backing storage of the ring buffer with `storage`, e.g. to
+    /// implement double-buffering, and returns the previous storage along with
+    /// the number of elements it still holds.
+    ///
+    /// Borrowing both handles mutably guarantees that no concurrent access can
+    /// happen during the swap. The ring buffer is empty after the swap, while
+    /// the elements it held are moved to the start of the returned storage in
+    /// FIFO order: the caller is responsible for draining or dropping them.
+    /// Elements pushed with
+    /// [`push_buffered`](RingBufferWriter::push_buffered) and not flushed
+    /// yet are moved along with the others. A storage not allocated as a box, e.g. by `init_on_node()` with the
+    /// `numa` feature, is copied into a new one.
+    ///
+    /// # Panics
+    /// Panics if the handles don't belong to the same ring buffer or if the
+    /// length of `storage` is not `N`.
+    pub fn swap_storage(
+        tx: &mut RingBufferWriter<T, N>,
+        rx: &mut RingBufferReader<T, N>,
+        storage: Box<[MaybeUninit<T>]>,
+    ) -> (Box<[MaybeUninit<T>]>, usize) {
+        assert!(
+            Arc::ptr_eq(&tx.inner, &rx.inner),
+            "The handles don't belong to the same RingBuffer."
+        );
+        let buffer = Storage::from_box(Self::into_buffer(storage));
+        // Publish the buffered elements, so that the write index is not left
+        // behind the read index set below
+        tx.flush();
+        let mut old = unsafe { mem::replace(&mut *tx.inner.buffer.get(), buffer) }.into_box();
+
+        let (idx_r, idx_w) = (rx.local_idx_r, tx.local_idx_w);
+        old.rotate_left(slot::<N>(idx_r));
+        tx.inner.idx_r.store(idx_w, Ordering::Release);
+        tx.cached_idx_r = idx_w;
+        (rx.local_idx_r, rx.cached_idx_w) = (idx_w, idx_w);
+
+        (old, distance(idx_r, idx_w))
     }
+
+    /// Reclaims the ring buffer of `tx` and `rx` once they are no longer
+    /// needed, so that it can be reused with
+    /// [`init_from_storage`](Self::init_from_storage) instead of allocating a
+    /// new one, e.g. to pool ring buffers across connections.
+    ///
+    /// The elements left in the ring buffer are dropped and the indexes are
+    /// reset to 0. Returns `None` if the ring buffer is still referenced
+    /// elsewhere, e.g. by a [`RingDescriptor`], in which case it is freed
+    /// once the last reference is dropped.
+    ///
+    /// # Panics
+    /// Panics if the handles don't belong to the same ring buffer.
+    pub fn recycle(
+        tx: RingBufferWriter<T, N>,
+        mut rx: RingBufferReader<T, N>,
+    ) -> Option<RecycledStorage<T, N>> {
+        assert!(
+            Arc::ptr_eq(&tx.inner, &rx.inner),
+            "The handles don't belong to the same RingBuffer."
+        );
+        let mut inner = tx.inner.clone();
+        // Dropping the writer first publishes any buffered element
+        drop(tx);
+        rx.clear();
+        drop(rx);
+
+        let rb = Arc::get_mut(&mut inner)?;
+        *rb.idx_r.get_mut() = 0;
+        *rb.idx_w.get_mut() = 0;
+        *rb.lock.get_mut() = false;
+        *rb.backpressure.get_mut() = 0;
+        *rb.disconnected.get_mut() = false;
+        #[cfg(feature = "async")]
+        {
+            rb.writer_waker = sink::WriterWaker::new();
+        }
+        #[cfg(feature = "metrics")]
+        {
+            rb.metrics = Metrics::default();
+        }
+        Some(RecycledStorage { inner })
+    }
+
+    /// Creates a ring buffer reusing the storage reclaimed by
+    /// [`recycle`](Self::recycle), without allocating.
+    pub fn init_from_storage(
+        storage: RecycledStorage<T, N>,
+    ) -> (RingBufferWriter<T, N>, RingBufferReader<T, N>) {
+        (
+            RingBufferWriter::new(storage.inner.clone()),
+            RingBufferReader::new(storage.inner),
+        )
+    }
+}
+
+/// An empty ring buffer reclaimed by [`RingBuffer::recycle`], to be reused
+/// with [`RingBuffer::init_from_storage`].
+pub struct RecycledStorage<T, const N: usize> {
+    inner: Arc<RingBuffer<T, N>>,
 }
 
 impl<T, const N: usize> Drop for RingBuffer<T, N> {
     fn drop(&mut self) {
-        let mut idx_r = self.idx_r.load(Ordering::Acquire);
+        let idx_r = self.idx_r.load(Ordering::Acquire);
         let idx_w = self.idx_w.load(Ordering::Acquire);
+        unsafe { drop_range(self.slots(), idx_r, idx_w) };
+    }
+}
+
+/// Counters only updated by either the writer or the reader. They are padded
+/// to not introduce false sharing between the two.
+#[cfg(feature = "metrics")]
+#[derive(Default)]
+struct Metrics {
+    writer_cache_misses: CachePadded<Counter>,
+    reader_cache_misses: CachePadded<Counter>,
+}
+
+/// A counter with a single writer, which thus doesn't need atomic increments.
+#[cfg(feature = "metrics")]
+#[derive(Default)]
+struct Counter(core::sync::atomic::AtomicUsize);
+
+#[cfg(feature = "metrics")]
+impl Counter {
+    #[inline]
+    fn incr(&self) {
+        self.0.store(
+            self.0.load(Ordering::Relaxed).wrapping_add(1),
+            Ordering::Relaxed,
+        );
+    }
+
+    #[inline]
+    fn get(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Returns the power-of-two capacity needed to buffer `target_buffer_secs`
+/// seconds worth of elements produced at `rate_per_sec` elements per second.
+///
+/// E.g. buffering 50ms of audio sampled at 48kHz:
+/// ```
+/// use ringbuffer_spsc::{suggested_capacity, RingBuffer};
+///
+/// const N: usize = suggested_capacity(48_000.0, 0.05);
+/// let (tx, rx) = RingBuffer::<f32, N>::init();
+/// ```
+pub const fn suggested_capacity(rate_per_sec: f64, target_buffer_secs: f64) -> usize {
+    let len = rate_per_sec * target_buffer_secs;
+    // Round up, the cast saturates and maps NaN to 0
+    let n = len as usize;
+    let n = if (n as f64) < len { n + 1 } else { n };
+    n.next_power_of_two()
+}
+
+/// Fails to compile if the slots of a [`RingBuffer<T, N>`] take more than
+/// `MAX_BYTES` bytes, to guard a memory budget on constrained targets.
+///
+/// ```
+/// use ringbuffer_spsc::{assert_buffer_fits, RingBuffer};
+///
+/// const _: () = assert_buffer_fits::<u32, 256, 1024>();
+/// let (tx, rx) = RingBuffer::<u32, 256>::init();
+/// ```
+///
+/// ```compile_fail
+/// use ringbuffer_spsc::assert_buffer_fits;
+///
+/// const _: () = assert_buffer_fits::<u32, 512, 1024>();
+/// ```
+pub const fn assert_buffer_fits<T, const N: usize, const MAX_BYTES: usize>() {
+    const {
+        assert!(
+            mem::size_of::<[T; N]>() <= MAX_BYTES,
+            "The ring buffer exceeds the memory budget."
+        )
+    };
+}
+
+/// The points at which the callbacks set with [`RingBufferWriter::set_sync_hook`]
+/// and [`RingBufferReader::set_sync_hook`] are invoked.
+///
+/// Hooks allow tests to deterministically interleave the writer and the reader
+/// from a single thread, e.g. by operating on the peer handle from within the
+/// hook. They are only available with the `test-util` feature.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SyncPoint {
+    /// Before the writer loads the read index.
+    WriterLoad,
+    /// After the writer stores the write index.
+    WriterStore,
+    /// Before the reader loads the write index.
+    ReaderLoad,
+    /// After the reader stores the read index.
+    ReaderStore,
+}
+
+#[cfg(feature = "debug-checks")]
+type Validator<T> = Box<dyn Fn(&T) -> bool + Send>;
+
+#[cfg(any(test, feature = "test-util"))]
+type SyncHook = Box<dyn FnMut(SyncPoint) + Send>;
+
+/// A callback fired when the occupancy crosses a level, see
+/// [`RingBufferWriter::on_high_watermark`] and
+/// [`RingBufferReader::on_low_watermark`].
+struct Watermark {
+    level: usize,
+    // Whether the occupancy is on the other side of the level, i.e. whether
+    // the next crossing fires the callback.
+    armed: bool,
+    f: Box<dyn FnMut() + Send>,
+}
+
+impl Watermark {
+    #[inline]
+    fn update(&mut self, crossed: bool) {
+        if crossed && self.armed {
+            (self.f)();
+        }
+        self.armed = !crossed;
+    }
+}
+
+/// The reason why a capacity is not valid for a [`RingBuffer`] or a
+/// [`RingBufferAny`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapacityError {
+    /// The capacity is zero.
+    Zero,
+    /// The capacity is not a power of two.
+    NotPowerOfTwo,
+    /// The capacity exceeds 2^31, which is only possible with the
+    /// `u32-indices` feature, or half the address space for a
+    /// [`RingBufferAny`].
+    TooLarge,
+}
+
+impl CapacityError {
+    const fn check(n: usize) -> Result<(), Self> {
+        if n == 0 {
+            Err(Self::Zero)
+        } else if !n.is_power_of_two() {
+            Err(Self::NotPowerOfTwo)
+        } else if cfg!(feature = "u32-indices") && n > 1 << 31 {
+            Err(Self::TooLarge)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Zero => f.write_str("capacity must be a non-zero power of two"),
+            Self::NotPowerOfTwo => f.write_str("capacity must be a power of two"),
+            Self::TooLarge => f.write_str("capacity must be at most 2^31 with `u32-indices`"),
+        }
+    }
+}
+
+impl core::error::Error for CapacityError {}
+
+/// The reason why [`RingBufferReader::try_pull`] returned no element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PullError {
+    /// The ring buffer is empty but the writer may still push.
+    Empty,
+    /// The ring buffer is empty and the writer has been dropped.
+    Disconnected,
+}
+
+impl fmt::Display for PullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("ring buffer is empty"),
+            Self::Disconnected => f.write_str("ring buffer is empty and the writer is gone"),
+        }
+    }
+}
+
+impl core::error::Error for PullError {}
+
+/// The outcome of [`RingBufferReader::pull_signal`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PullSignal<T> {
+    /// The element was pulled from a non-full ring buffer: the writer is
+    /// busy and doesn't need to be woken up.
+    RemovedNoWake(T),
+    /// The element was pulled from a full ring buffer: the writer may be
+    /// waiting for a free slot.
+    RemovedWake(T),
+    /// The ring buffer is empty.
+    Empty,
+}
 
-        while idx_r != idx_w {
-            let t =
-                unsafe { mem::replace(self.get_mut(idx_r), MaybeUninit::uninit()).assume_init() };
-            mem::drop(t);
-            idx_r = idx_r.wrapping_add(1);
+/// The reason why [`RingBufferWriter::try_push`] gave an element back.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushError<T> {
+    /// The ring buffer is full.
+    Full(T),
+    /// The ring buffer is full and the reader has been dropped.
+    Disconnected(T),
+}
+
+impl<T> PushError<T> {
+    /// Returns the element that could not be pushed.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Full(t) | Self::Disconnected(t) => t,
+        }
+    }
+}
+
+impl<T> fmt::Display for PushError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full(_) => f.write_str("ring buffer is full"),
+            Self::Disconnected(_) => f.write_str("ring buffer is full and the reader is gone"),
         }
     }
 }
 
-pub struct RingBufferWriter<T, const N: usize> {
+impl<T: fmt::Debug> core::error::Error for PushError<T> {}
+
+/// The outcome of [`RingBufferWriter::push_signal`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushSignal<T> {
+    /// The element was pushed into a non-empty ring buffer: the reader is
+    /// busy and doesn't need to be woken up.
+    StoredNoWake,
+    /// The element was pushed into an empty ring buffer: the reader may be
+    /// waiting for it.
+    StoredWake,
+    /// The ring buffer is full and the element is given back, whatever the
+    /// [`FullPolicy`].
+    Full(T),
+}
+
+/// What [`RingBufferWriter::push`] does when the ring buffer is full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FullPolicy {
+    /// The element is rejected and given back to the caller.
+    #[default]
+    Reject,
+    /// The oldest element in the buffer is dropped to make room for the new
+    /// one. Only available on buffers created with [`RingBuffer::init_lossy`].
+    DropOldest,
+    /// The new element is dropped.
+    DropNewest,
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The overflow behavior of a [`RingBufferWriter`], selected at compile time.
+///
+/// This trait is sealed: it is implemented by [`Reject`] and [`Overwrite`]
+/// only.
+pub trait OverflowPolicy: sealed::Sealed {
+    #[doc(hidden)]
+    const OVERWRITE: bool;
+}
+
+/// The default [`OverflowPolicy`]: a full ring buffer is handled according to
+/// the runtime [`FullPolicy`] of the writer, which rejects elements unless
+/// configured otherwise.
+pub struct Reject;
+
+/// An [`OverflowPolicy`] always overwriting the oldest element when the ring
+/// buffer is full. Writers with this policy are created by
+/// [`RingBuffer::init_overwrite`].
+pub struct Overwrite;
+
+impl sealed::Sealed for Reject {}
+impl sealed::Sealed for Overwrite {}
+
+impl OverflowPolicy for Reject {
+    const OVERWRITE: bool = false;
+}
+
+impl OverflowPolicy for Overwrite {
+    const OVERWRITE: bool = true;
+}
+
+/// The outcome of a successful [`RingBufferWriter::push_with_space`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PushOk {
+    /// The element was stored and at least this many slots are still free.
+    StoredWithSpace(usize),
+    /// The element was stored in the last free slot.
+    StoredNowFull,
+}
+
+pub struct RingBufferWriter<T, const N: usize, P = Reject> {
     inner: Arc<RingBuffer<T, N>>,
-    cached_idx_r: usize,
-    local_idx_w: usize,
+    cached_idx_r: Index,
+    local_idx_w: Index,
+    full_policy: FullPolicy,
+    // Whether the write index is advanced by external code, see
+    // `raw_write_index()`.
+    raw: bool,
+    #[cfg(feature = "debug-checks")]
+    validator: Option<Validator<T>>,
+    // The last read index observed and when it was first observed.
+    #[cfg(all(feature = "std", feature = "metrics"))]
+    consumer_progress: (Index, std::time::Instant),
+    #[cfg(all(feature = "std", feature = "metrics"))]
+    drain_rate: blocking::DrainRate,
+    high_watermark: Option<Watermark>,
+    #[cfg(feature = "replay-log")]
+    log: replay::ReplayLog,
+    #[cfg(any(test, feature = "test-util"))]
+    hook: Option<SyncHook>,
+    _policy: PhantomData<P>,
 }
 
-impl<T, const N: usize> RingBufferWriter<T, N> {
+impl<T, const N: usize, P: OverflowPolicy> RingBufferWriter<T, N, P> {
+    fn new(inner: Arc<RingBuffer<T, N>>) -> Self {
+        Self {
+            inner,
+            cached_idx_r: 0,
+            local_idx_w: 0,
+            full_policy: FullPolicy::Reject,
+            raw: false,
+            #[cfg(feature = "debug-checks")]
+            validator: None,
+            #[cfg(all(feature = "std", feature = "metrics"))]
+            consumer_progress: (0, std::time::Instant::now()),
+            #[cfg(all(feature = "std", feature = "metrics"))]
+            drain_rate: blocking::DrainRate::new(),
+            high_watermark: None,
+            #[cfg(feature = "replay-log")]
+            log: replay::ReplayLog::new(),
+            #[cfg(any(test, feature = "test-util"))]
+            hook: None,
+            _policy: PhantomData,
+        }
+    }
+
+    /// Returns a read-only [`RingDescriptor`] of this ring buffer.
+    pub fn descriptor(&self) -> RingDescriptor<T, N> {
+        RingDescriptor {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Returns `false` once the reader has been dropped, after which pushed
+    /// elements are never pulled.
+    ///
+    /// The reference count of the shared state can't tell, since it also
+    /// accounts for the [`RingDescriptor`]s.
+    pub fn is_reader_alive(&self) -> bool {
+        !self.inner.disconnected.load(Ordering::Acquire)
+    }
+
+    /// Sets a callback fired by the writer when the occupancy reaches `level`
+    /// after having been below it, e.g. to start shedding load.
+    ///
+    /// The callback fires once per crossing rather than on every push while
+    /// the occupancy stays at or above `level`. While it does, every push
+    /// loads the read index to detect when the occupancy drops again.
+    pub fn on_high_watermark(&mut self, level: usize, f: impl FnMut() + Send + 'static) {
+        self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        self.high_watermark = Some(Watermark {
+            level,
+            armed: distance(self.cached_idx_r, self.local_idx_w) < level,
+            f: Box::new(f),
+        });
+    }
+
     #[inline]
-    pub fn push(&mut self, t: T) -> Option<T> {
+    fn check_high_watermark(&mut self) {
+        if let Some(wm) = self.high_watermark.as_mut() {
+            // The cached read index makes this an upper bound of the occupancy
+            let mut len = distance(self.cached_idx_r, self.local_idx_w);
+            if len >= wm.level {
+                self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+                len = distance(self.cached_idx_r, self.local_idx_w);
+            }
+            wm.update(len >= wm.level);
+        }
+    }
+
+    /// Returns the most recent operations of the writer, oldest first.
+    ///
+    /// Each push path publishing the write index records one event, e.g. a
+    /// single event for a whole [`push_slice`](Self::push_slice). At least
+    /// the last 512 events are kept.
+    #[cfg(feature = "replay-log")]
+    pub fn replay_log(&self) -> &[ReplayEvent] {
+        self.log.events()
+    }
+
+    /// Sets a callback invoked at the writer's synchronization points.
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn set_sync_hook(&mut self, hook: impl FnMut(SyncPoint) + Send + 'static) {
+        self.hook = Some(Box::new(hook));
+    }
+
+    #[cfg(any(test, feature = "test-util"))]
+    #[inline]
+    fn sync_point(&mut self, point: SyncPoint) {
+        if let Some(hook) = self.hook.as_mut() {
+            hook(point);
+        }
+    }
+
+    /// Sets an invariant checker run on every element pushed into the ring
+    /// buffer, to catch invalid data at the producer rather than at the
+    /// consumer.
+    ///
+    /// This is a no-op unless the `debug-checks` feature is enabled.
+    ///
+    /// # Panics
+    /// With `debug-checks`, pushing an element for which `f` returns `false`
+    /// panics.
+    pub fn set_validator(&mut self, f: impl Fn(&T) -> bool + Send + 'static) {
+        #[cfg(feature = "debug-checks")]
+        {
+            self.validator = Some(Box::new(f));
+        }
+        #[cfg(not(feature = "debug-checks"))]
+        let _ = f;
+    }
+
+    #[cfg(feature = "debug-checks")]
+    #[inline]
+    fn validate(&self, t: &T) {
+        if let Some(f) = self.validator.as_ref() {
+            assert!(
+                f(t),
+                "RingBufferWriter: element pushed at index {} failed validation.",
+                self.local_idx_w
+            );
+        }
+    }
+
+    /// Returns the number of times the writer found its cached read index
+    /// insufficient and had to load it from the shared state.
+    #[cfg(feature = "metrics")]
+    pub fn cache_miss_count(&self) -> usize {
+        self.inner.metrics.writer_cache_misses.get()
+    }
+
+    /// Returns for how long the reader has not pulled any element while some
+    /// were available, or `None` if it has pulled since the last call or if
+    /// the buffer is empty.
+    ///
+    /// The progress of the reader is only sampled by this method, so the
+    /// duration is measured from the first call observing the current read
+    /// index, and calling it periodically gives the best accuracy.
+    #[cfg(all(feature = "std", feature = "metrics"))]
+    pub fn consumer_stall_duration(&mut self) -> Option<std::time::Duration> {
+        let idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        self.cached_idx_r = idx_r;
+        if idx_r != self.consumer_progress.0 || idx_r == self.local_idx_w {
+            self.consumer_progress = (idx_r, std::time::Instant::now());
+            return None;
+        }
+        Some(self.consumer_progress.1.elapsed())
+    }
+
+    /// Returns the backpressure level last signaled by the reader with
+    /// [`RingBufferReader::signal_backpressure`], 0 if none.
+    pub fn backpressure_level(&mut self) -> u8 {
+        self.inner.backpressure.load(Ordering::Relaxed)
+    }
+
+    /// Returns the current read index of the reader, i.e. the total number of
+    /// elements pulled so far (wrapping at `usize::MAX`, or `u32::MAX` with the
+    /// `u32-indices` feature), and caches it.
+    ///
+    /// This always loads the read index from the shared state, so it can be
+    /// used to monitor how far behind the reader is.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn consumer_position(&mut self) -> usize {
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::WriterLoad);
+        self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        self.cached_idx_r as usize
+    }
+
+    /// Returns a pointer to the first slot of the backing storage, e.g. to
+    /// register it for kernel writes along with [`raw_write_index`](Self::raw_write_index).
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.inner.as_ptr()
+    }
+
+    /// Returns the shared write index, for external code filling the slots
+    /// on behalf of the writer, e.g. an `io_uring` completion handler.
+    ///
+    /// The index counts the elements pushed so far and wraps around at
+    /// `usize::MAX` (`u32::MAX` with the `u32-indices` feature). Slot `i` of
+    /// the storage holds the element at every index `idx` such that
+    /// `idx & (N - 1) == i`.
+    ///
+    /// # Safety
+    /// External code advancing the index from `w` to `w + k` must:
+    /// - only write the slots of indexes `w..w + k`, which must all be free,
+    ///   i.e. `w + k - r <= N` for a read index `r` loaded with `Acquire`;
+    /// - initialize all of them before storing `w + k` with `Release`
+    ///   ordering, which publishes them to the reader;
+    /// - be the only one advancing the index, i.e. this writer must not push
+    ///   anymore since its own copy of the index is then stale. Dropping the
+    ///   writer leaves the index untouched.
+    pub unsafe fn raw_write_index(&mut self) -> &AtomicIndex {
+        self.raw = true;
+        &self.inner.idx_w
+    }
+
+    /// Returns `true` if the ring buffer is full.
+    ///
+    /// The read index is loaded only if the buffer appears to be full
+    /// according to the cached one.
+    #[inline]
+    pub fn is_full(&mut self) -> bool {
         // Check if the ring buffer is potentially full.
         // This happens when the difference between the write and read indexes equals
         // the ring buffer capacity. Note that the write and read indexes are left growing
         // indefinitely, so we need to compute the difference by accounting for any eventual
         // overflow. This requires wrapping the subtraction operation.
-        if self.local_idx_w.wrapping_sub(self.cached_idx_r) == N {
-            self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
-            // Check if the ring buffer is really full
-            if self.local_idx_w.wrapping_sub(self.cached_idx_r) == N {
-                return Some(t);
+        is_full::<N>(self.cached_idx_r, self.local_idx_w) && self.refresh_is_full()
+    }
+
+    /// Slow path of [`is_full`](Self::is_full), kept out of line so that the
+    /// fast path stays small in tight push loops.
+    #[cold]
+    #[inline(never)]
+    fn refresh_is_full(&mut self) -> bool {
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.writer_cache_misses.incr();
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::WriterLoad);
+        self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        // Check if the ring buffer is really full
+        is_full::<N>(self.cached_idx_r, self.local_idx_w)
+    }
+
+    /// Returns `true` if the ring buffer is full, without requiring exclusive
+    /// access to the writer, e.g. to report metrics.
+    ///
+    /// Unlike [`is_full`](Self::is_full), the read index is loaded every time
+    /// instead of being cached. This is meant for observation only: the push
+    /// paths should rely on `is_full()`.
+    pub fn is_full_relaxed(&self) -> bool {
+        is_full::<N>(self.inner.idx_r.load(Ordering::Acquire), self.local_idx_w)
+    }
+
+    /// Returns the size in bytes of the storage of the elements, see
+    /// [`RingBuffer::CAPACITY_BYTES`].
+    pub const fn capacity_bytes(&self) -> usize {
+        RingBuffer::<T, N>::CAPACITY_BYTES
+    }
+
+    /// Returns the size in bytes allocated besides the storage of the
+    /// elements, see [`RingBuffer::OVERHEAD_BYTES`].
+    pub const fn overhead_bytes(&self) -> usize {
+        RingBuffer::<T, N>::OVERHEAD_BYTES
+    }
+
+    /// Returns the number of free slots in the ring buffer.
+    ///
+    /// As with [`is_full`](Self::is_full), the read index is loaded only if
+    /// the buffer appears to be full according to the cached one. Since the
+    /// reader runs concurrently, this is a lower bound.
+    pub fn remaining_capacity(&mut self) -> usize {
+        if self.is_full() {
+            return 0;
+        }
+        N - distance(self.cached_idx_r, self.local_idx_w)
+    }
+
+    /// Pushes an element into the ring buffer.
+    ///
+    /// If the buffer is full, the outcome depends on the configured
+    /// [`FullPolicy`]: with `Reject` the element is given back as `Some(t)`,
+    /// with `DropOldest` the oldest element is dropped and `t` is stored, and
+    /// with `DropNewest` `t` is dropped. `None` is returned in the last two cases.
+    ///
+    /// Writers with the [`Overwrite`] policy always drop the oldest element
+    /// instead, regardless of the [`FullPolicy`].
+    #[inline]
+    pub fn push(&mut self, t: T) -> Option<T> {
+        self.try_push(t).err().map(PushError::into_inner)
+    }
+
+    /// Pushes an element into the ring buffer like [`push`](Self::push), but
+    /// tells why the element is given back.
+    ///
+    /// To keep the fast path free of extra loads, the liveness of the reader
+    /// is only checked when the buffer is full: elements pushed after the
+    /// reader has been dropped are stored until then.
+    #[inline]
+    pub fn try_push(&mut self, t: T) -> Result<(), PushError<T>> {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        if self.is_full() {
+            if P::OVERWRITE {
+                mem::drop(self.evict());
+            } else {
+                match self.full_policy {
+                    FullPolicy::Reject if self.is_reader_alive() => return Err(PushError::Full(t)),
+                    FullPolicy::Reject => return Err(PushError::Disconnected(t)),
+                    FullPolicy::DropNewest => return Ok(()),
+                    FullPolicy::DropOldest => mem::drop(self.evict()),
+                }
             }
         }
 
+        #[cfg(feature = "debug-checks")]
+        self.validate(&t);
         // Insert the element in the ring buffer
-        unsafe { mem::replace(self.inner.get_mut(self.local_idx_w), MaybeUninit::new(t)) };
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
         // Let's increment the counter and let it grow indefinitely and potentially overflow resetting it to 0.
-        self.local_idx_w = self.local_idx_w.wrapping_add(1);
-        self.inner.idx_w.store(self.local_idx_w, Ordering::Release);
+        self.advance(1);
+
+        Ok(())
+    }
+
+    /// Pushes an element like [`try_push`](Self::try_push), and reports
+    /// whether it made the ring buffer non-empty, so that a reader sleeping
+    /// while the buffer is empty is woken up only when needed.
+    ///
+    /// The write index is published before the read index is loaded, with a
+    /// sequentially consistent fence in between. A reader going to sleep must
+    /// likewise check that the buffer is still empty after announcing itself,
+    /// with a fence of its own, so that either it sees the element or the
+    /// writer sees it waiting for one.
+    ///
+    /// With [`FullPolicy::DropNewest`], the element that would be dropped is
+    /// given back as [`PushSignal::Full`] instead, so that it is never
+    /// reported as stored.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::{PushSignal, RingBuffer};
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    /// assert_eq!(tx.push_signal(0), PushSignal::StoredWake);
+    /// assert_eq!(tx.push_signal(1), PushSignal::StoredNoWake);
+    /// assert_eq!(tx.push_signal(2), PushSignal::Full(2));
+    /// ```
+    pub fn push_signal(&mut self, t: T) -> PushSignal<T> {
+        if !P::OVERWRITE && self.full_policy == FullPolicy::DropNewest && self.is_full() {
+            return PushSignal::Full(t);
+        }
+        let idx_w = self.local_idx_w;
+        if let Err(e) = self.try_push(t) {
+            return PushSignal::Full(e.into_inner());
+        }
+        atomic::fence(Ordering::SeqCst);
+        self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        if self.cached_idx_r == idx_w {
+            PushSignal::StoredWake
+        } else {
+            PushSignal::StoredNoWake
+        }
+    }
+
+    /// Pushes an element into the ring buffer, evicting the oldest element if
+    /// the buffer is full, and returns the evicted element if any.
+    ///
+    /// This is [`FullPolicy::DropOldest`] handing the evicted element back,
+    /// e.g. to account for drops, regardless of the configured policy.
+    ///
+    /// # Panics
+    /// Panics if the buffer was not created with [`RingBuffer::init_lossy`]
+    /// or [`RingBuffer::init_overwrite`], as evicting elements from the writer
+    /// requires the reader to synchronize with it.
+    pub fn push_overwrite(&mut self, t: T) -> Option<T> {
+        assert!(
+            self.inner.lossy,
+            "push_overwrite() requires a RingBuffer created with `init_lossy()`."
+        );
+        let evicted = if self.is_full() { self.evict() } else { None };
+        #[cfg(feature = "debug-checks")]
+        self.validate(&t);
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.advance(1);
 
+        evicted
+    }
+
+    /// Pushes an element into the ring buffer without publishing it: the
+    /// element becomes visible to the reader only on the next
+    /// [`flush`](Self::flush), or when the writer is dropped. This amortizes
+    /// the cost of publishing the write index over a batch of elements.
+    ///
+    /// Returns the element back if the ring buffer is full, regardless of the
+    /// [`FullPolicy`], since making room would require the reader to catch up
+    /// with elements it cannot see yet.
+    ///
+    /// Forgetting to [`flush`](Self::flush) leaves a reader waiting for the
+    /// buffered elements blocked forever, and a writer waiting for room
+    /// blocked as well once the buffer fills up with them.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    /// assert!(tx.push_buffered(0).is_none());
+    /// assert!(tx.push_buffered(1).is_none());
+    /// assert_eq!(rx.pull(), None);
+    /// tx.flush();
+    /// assert_eq!(rx.pull(), Some(0));
+    /// assert_eq!(rx.pull(), Some(1));
+    /// ```
+    pub fn push_buffered(&mut self, t: T) -> Option<T> {
+        if self.is_full() {
+            return Some(t);
+        }
+        #[cfg(feature = "debug-checks")]
+        self.validate(&t);
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.stage(1);
         None
     }
-}
 
-pub struct RingBufferReader<T, const N: usize> {
-    inner: Arc<RingBuffer<T, N>>,
-    local_idx_r: usize,
-    cached_idx_w: usize,
-}
+    /// Pushes elements taken from `iter` until the ring buffer is full or
+    /// `iter` is exhausted, and returns how many were pushed, regardless of
+    /// the configured [`FullPolicy`]. Unlike [`push_slice`](Self::push_slice),
+    /// this moves the elements and thus doesn't require `T: Copy`.
+    ///
+    /// No element is taken from `iter` once the ring buffer is full, so the
+    /// elements left can be pushed later. The write index is published once
+    /// for all the elements.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<String, 2>::init();
+    /// let mut iter = ["a", "b", "c"].map(String::from).into_iter();
+    /// assert_eq!(tx.push_iter(&mut iter), 2);
+    /// assert_eq!(iter.next().as_deref(), Some("c"));
+    /// assert_eq!(rx.pull().as_deref(), Some("a"));
+    /// ```
+    pub fn push_iter<I: Iterator<Item = T>>(&mut self, iter: &mut I) -> usize {
+        let mut n = 0;
+        while !self.is_full() {
+            let Some(t) = iter.next() else {
+                break;
+            };
+            #[cfg(feature = "debug-checks")]
+            self.validate(&t);
+            unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+            self.stage(1);
+            n += 1;
+        }
+        if n > 0 {
+            self.flush();
+        }
+        n
+    }
 
-impl<T, const N: usize> RingBufferReader<T, N> {
+    /// Publishes the write index, making every element pushed with
+    /// [`push_buffered`](Self::push_buffered) visible to the reader at once.
     #[inline]
-    pub fn pull(&mut self) -> Option<T> {
-        // Check if the ring buffer is potentially empty
-        if self.local_idx_r == self.cached_idx_w {
-            // Update the write index
-            self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
-            // Check if the ring buffer is really empty
-            if self.local_idx_r == self.cached_idx_w {
-                return None;
+    pub fn flush(&mut self) {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        self.inner.idx_w.store(self.local_idx_w, Ordering::Release);
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::WriterStore);
+        self.check_high_watermark();
+    }
+
+    /// Returns `true` if a [`push`](Self::push) would not store the element
+    /// because the ring buffer is full. This is the same as
+    /// [`is_full`](Self::is_full), and reads better when guarding the
+    /// construction of an expensive element:
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, _rx) = RingBuffer::<Vec<u8>, 4>::init();
+    /// if !tx.would_block() {
+    ///     assert!(tx.push_with(|| vec![0; 4096]).is_none());
+    /// }
+    /// ```
+    #[inline]
+    pub fn would_block(&mut self) -> bool {
+        self.is_full()
+    }
+
+    /// Pushes the element returned by `f`, which is invoked only if there is
+    /// room for it.
+    ///
+    /// This behaves like [`push`](Self::push) but gives `f` back instead of
+    /// the element when the element is not stored.
+    #[inline]
+    pub fn push_with<F: FnOnce() -> T>(&mut self, f: F) -> Option<F> {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        if self.is_full() {
+            if P::OVERWRITE {
+                mem::drop(self.evict());
+            } else {
+                match self.full_policy {
+                    FullPolicy::Reject => return Some(f),
+                    FullPolicy::DropNewest => return None,
+                    FullPolicy::DropOldest => mem::drop(self.evict()),
+                }
+            }
+        }
+        let t = f();
+        #[cfg(feature = "debug-checks")]
+        self.validate(&t);
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.advance(1);
+
+        None
+    }
+
+    /// Pushes the elements of `items` in order as far as they fit, and gives
+    /// back the ones that didn't fit, regardless of the configured
+    /// [`FullPolicy`].
+    pub fn push_batch_report(&mut self, mut items: Vec<T>) -> Vec<T> {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        let (a, b) = self.free_slices(items.len());
+        let n = items.len().min(a.len() + b.len());
+        for (slot, t) in a.iter_mut().chain(b).zip(items.drain(..n)) {
+            slot.write(t);
+        }
+        // The written elements are not visible to the reader until advancing
+        #[cfg(feature = "debug-checks")]
+        for i in 0..n {
+            self.validate(unsafe {
+                self.inner
+                    .get(forward(self.local_idx_w, i))
+                    .assume_init_ref()
+            });
+        }
+        self.advance(n);
+
+        items
+    }
+
+    /// Returns a scratch slot in which an element can be built in place, to
+    /// then be moved into the ring buffer with
+    /// [`commit_scratch`](Self::commit_scratch).
+    ///
+    /// Unlike a ring slot, the scratch slot is available regardless of the
+    /// occupancy of the buffer. An element left in it is never dropped.
+    pub fn scratch_mut(&mut self) -> &mut MaybeUninit<T> {
+        // The scratch slot is only accessed by the writer.
+        unsafe { &mut *self.inner.scratch.get() }
+    }
+
+    /// Moves the element built in the scratch slot into the ring buffer and
+    /// returns `true`, or returns `false` if the buffer is full, in which case
+    /// the element is retained in the scratch slot.
+    ///
+    /// The element is moved regardless of the configured [`FullPolicy`].
+    ///
+    /// # Safety
+    /// The scratch slot must have been initialized with
+    /// [`scratch_mut`](Self::scratch_mut). Once committed, the element is
+    /// moved out and the slot must be initialized again before committing.
+    pub unsafe fn commit_scratch(&mut self) -> bool {
+        if self.is_full() {
+            return false;
+        }
+        #[cfg(feature = "debug-checks")]
+        self.validate((*self.inner.scratch.get()).assume_init_ref());
+        // Copy the element directly to avoid a temporary for large T
+        ptr::copy_nonoverlapping(
+            self.inner.scratch.get(),
+            self.inner.get_mut(self.local_idx_w),
+            1,
+        );
+        self.advance(1);
+
+        true
+    }
+
+    /// Returns the largest contiguous run of free slots starting at the write
+    /// index, in which elements can be built in place and then published with
+    /// [`commit`](Self::commit), e.g. by a DMA-style fill.
+    ///
+    /// The run stops at the end of the backing storage, so it may be shorter
+    /// than the free space when the latter wraps around.
+    pub fn writable_slice(&mut self) -> &mut [MaybeUninit<T>] {
+        let end = N - slot::<N>(self.local_idx_w);
+        self.free_slices(end).0
+    }
+
+    /// Publishes the first `n` slots of the run returned by
+    /// [`writable_slice`](Self::writable_slice) with a single store of the
+    /// write index, regardless of the configured [`FullPolicy`].
+    ///
+    /// # Safety
+    /// The first `n` slots of the run must have been initialized. Elements
+    /// written past them are ignored and never dropped.
+    ///
+    /// # Panics
+    /// Panics if `n` exceeds the length of the run.
+    pub unsafe fn commit(&mut self, n: usize) {
+        let free = N - distance(self.cached_idx_r, self.local_idx_w);
+        let run = free.min(N - slot::<N>(self.local_idx_w));
+        assert!(
+            n <= run,
+            "RingBufferWriter: cannot commit {n} elements, only {run} slots were writable."
+        );
+        #[cfg(feature = "debug-checks")]
+        for i in 0..n {
+            self.validate(
+                self.inner
+                    .get(forward(self.local_idx_w, i))
+                    .assume_init_ref(),
+            );
+        }
+        self.advance(n);
+    }
+
+    /// Pushes an element into the ring buffer and reports whether it is now
+    /// full, or gives the element back if it was already full regardless of
+    /// the configured [`FullPolicy`].
+    ///
+    /// The read index is loaded only if the buffer appears to be full, so the
+    /// free space reported by [`PushOk::StoredWithSpace`] is a lower bound.
+    #[inline]
+    pub fn push_with_space(&mut self, t: T) -> Result<PushOk, T> {
+        if self.is_full() {
+            return Err(t);
+        }
+        #[cfg(feature = "debug-checks")]
+        self.validate(&t);
+        unsafe { self.inner.get_mut(self.local_idx_w).write(t) };
+        self.advance(1);
+
+        if self.is_full() {
+            Ok(PushOk::StoredNowFull)
+        } else {
+            Ok(PushOk::StoredWithSpace(
+                N - distance(self.cached_idx_r, self.local_idx_w),
+            ))
+        }
+    }
+
+    /// Removes and returns the oldest element if the buffer is still full.
+    #[cold]
+    fn evict(&mut self) -> Option<T> {
+        // The reader may be moving the oldest element out concurrently,
+        // hence the lock. The read index may have moved since it was cached.
+        self.inner.lock();
+        let idx_r = self.inner.idx_r.load(Ordering::Relaxed);
+        if distance(idx_r, self.local_idx_w) == N {
+            let t = unsafe {
+                mem::replace(self.inner.get_mut(idx_r), MaybeUninit::uninit()).assume_init()
+            };
+            self.cached_idx_r = idx_r.wrapping_add(1);
+            self.inner.idx_r.store(self.cached_idx_r, Ordering::Release);
+            self.inner.unlock();
+            Some(t)
+        } else {
+            self.cached_idx_r = idx_r;
+            self.inner.unlock();
+            None
+        }
+    }
+
+    /// Returns the free slots of the ring buffer as two slices, the second
+    /// one being non-empty only when the free region wraps around the end of
+    /// the buffer. The read index is refreshed only if fewer than `min` slots
+    /// appear to be free.
+    #[inline]
+    fn free_slices(&mut self, min: usize) -> (&mut [MaybeUninit<T>], &mut [MaybeUninit<T>]) {
+        if N - distance(self.cached_idx_r, self.local_idx_w) < min {
+            #[cfg(feature = "metrics")]
+            self.inner.metrics.writer_cache_misses.incr();
+            #[cfg(any(test, feature = "test-util"))]
+            self.sync_point(SyncPoint::WriterLoad);
+            self.cached_idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        }
+        let len = N - distance(self.cached_idx_r, self.local_idx_w);
+        let start = slot::<N>(self.local_idx_w);
+        let first = len.min(N - start);
+        // The slots between the write and read indexes are not accessed by
+        // the reader until the write index is advanced.
+        unsafe {
+            let ptr = self.inner.as_ptr() as *mut MaybeUninit<T>;
+            (
+                slice::from_raw_parts_mut(ptr.add(start), first),
+                slice::from_raw_parts_mut(ptr, len - first),
+            )
+        }
+    }
+
+    /// Advances the write index by `n` elements, which must have been
+    /// initialized already.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.stage(n);
+        self.flush();
+    }
+
+    /// Advances the local write index by `n` elements, which must have been
+    /// initialized already, without publishing it to the reader.
+    #[inline]
+    fn stage(&mut self, n: usize) {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        #[cfg(feature = "replay-log")]
+        self.log
+            .record(ReplayOp::Push, distance(0, self.local_idx_w), n);
+        #[cfg(feature = "timestamps")]
+        {
+            let now = std::time::Instant::now();
+            for i in 0..n {
+                let idx = slot::<N>(forward(self.local_idx_w, i));
+                // Like the slots, the stamps are owned by the writer until the
+                // write index is advanced.
+                unsafe { *self.inner.stamps[idx].get() = now };
+            }
+        }
+        self.local_idx_w = forward(self.local_idx_w, n);
+    }
+}
+
+impl<T, const N: usize, P> Drop for RingBufferWriter<T, N, P> {
+    fn drop(&mut self) {
+        // Make sure every element pushed is visible to the reader, even if a
+        // push path defers publishing the write index.
+        if !self.raw {
+            self.inner.idx_w.store(self.local_idx_w, Ordering::Release);
+        }
+        self.inner.disconnected.store(true, Ordering::Release);
+    }
+}
+
+impl<T, const N: usize> RingBufferWriter<T, N> {
+    /// Turns this writer into a [`MapWriter`] applying `f` to each element
+    /// before pushing it.
+    pub fn with<U, F: FnMut(U) -> T>(self, f: F) -> MapWriter<U, T, F, N> {
+        MapWriter {
+            inner: self,
+            f,
+            _u: PhantomData,
+        }
+    }
+
+    /// Returns the policy applied by [`push`](Self::push) when the buffer is full.
+    pub fn full_policy(&self) -> FullPolicy {
+        self.full_policy
+    }
+
+    /// Sets the policy applied by [`push`](Self::push) when the buffer is full.
+    ///
+    /// # Panics
+    /// Panics if `policy` is [`FullPolicy::DropOldest`] and the buffer was not
+    /// created with [`RingBuffer::init_lossy`].
+    pub fn set_full_policy(&mut self, policy: FullPolicy) {
+        assert!(
+            policy != FullPolicy::DropOldest || self.inner.lossy,
+            "FullPolicy::DropOldest requires a RingBuffer created with `init_lossy()`."
+        );
+        self.full_policy = policy;
+    }
+}
+
+impl<T: Copy, const N: usize, P: OverflowPolicy> RingBufferWriter<T, N, P> {
+    /// Copies as many elements of `src` as fit into the ring buffer and
+    /// returns their number, regardless of the configured [`FullPolicy`].
+    ///
+    /// The elements are copied with at most two memcpy's, and the write index
+    /// is published once for the whole slice.
+    pub fn push_slice(&mut self, src: &[T]) -> usize {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.writer_owner.enter("RingBufferWriter");
+        let (a, b) = self.free_slices(src.len());
+        let n = src.len().min(a.len() + b.len());
+        let (src_a, src_b) = src[..n].split_at(n.min(a.len()));
+        // SAFETY: MaybeUninit<T> has the same layout as T
+        unsafe {
+            ptr::copy_nonoverlapping(src_a.as_ptr(), a.as_mut_ptr() as *mut T, src_a.len());
+            ptr::copy_nonoverlapping(src_b.as_ptr(), b.as_mut_ptr() as *mut T, src_b.len());
+        }
+        // The copied elements are not visible to the reader until advancing
+        #[cfg(feature = "debug-checks")]
+        src[..n].iter().for_each(|t| self.validate(t));
+
+        self.advance(n);
+        n
+    }
+}
+
+impl<T: Copy, const N: usize, P: OverflowPolicy> RingBufferWriter<T, N, P> {
+    /// Pushes all the elements of `batch` and returns `true`, or none of them
+    /// and returns `false` if they don't all fit, regardless of the configured
+    /// [`FullPolicy`].
+    ///
+    /// A batch larger than the capacity could never be pushed, so it is
+    /// rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, _rx) = RingBuffer::<u8, 4>::init();
+    /// tx.push_batch(&[0; 8]);
+    /// ```
+    pub fn push_batch<const B: usize>(&mut self, batch: &[T; B]) -> bool {
+        const { assert!(B <= N, "The batch size exceeds the capacity.") };
+        let (a, b) = self.free_slices(B);
+        if a.len() + b.len() < B {
+            return false;
+        }
+        self.push_slice(batch);
+        true
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize, P: OverflowPolicy> RingBufferWriter<T, N, P> {
+    /// Pushes the elements of `src` up to and including the first occurrence
+    /// of `sentinel`, and returns the number of elements pushed.
+    ///
+    /// Fewer elements are pushed if the ring buffer gets full, and the whole
+    /// `src` is pushed (as far as it fits) if it doesn't contain `sentinel`.
+    pub fn push_slice_until(&mut self, src: &[T], sentinel: T) -> usize {
+        let len = src
+            .iter()
+            .position(|t| *t == sentinel)
+            .map_or(src.len(), |p| p + 1);
+        self.push_slice(&src[..len])
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> RingBufferWriter<u8, N, P> {
+    /// Reads bytes from `r` straight into the free space of the ring buffer
+    /// and returns their number, regardless of the configured [`FullPolicy`].
+    ///
+    /// The free space is handed to [`read_vectored`](std::io::Read::read_vectored)
+    /// as two regions when it wraps around the end of the buffer. A single
+    /// read is issued, retried only if interrupted, so fewer bytes than the
+    /// free space may be read. The free space is the one seen through the
+    /// cached read index, which is only refreshed if the buffer seems full.
+    #[cfg(feature = "std")]
+    pub fn fill_from_reader<R: std::io::Read>(&mut self, r: &mut R) -> std::io::Result<usize> {
+        use std::io::{ErrorKind, IoSliceMut};
+
+        // The cached read index is only refreshed if the buffer seems full
+        let (a, b) = self.free_slices(1);
+        let max = a.len() + b.len();
+        // Reading into uninitialized memory is not possible on stable yet
+        a.fill(MaybeUninit::new(0));
+        b.fill(MaybeUninit::new(0));
+        // SAFETY: MaybeUninit<u8> has the same layout as u8, and the bytes
+        // have just been initialized.
+        let (a, b) = unsafe {
+            (
+                slice::from_raw_parts_mut(a.as_mut_ptr() as *mut u8, a.len()),
+                slice::from_raw_parts_mut(b.as_mut_ptr() as *mut u8, b.len()),
+            )
+        };
+        let n = loop {
+            match r.read_vectored(&mut [IoSliceMut::new(a), IoSliceMut::new(b)]) {
+                Ok(n) => break n.min(max),
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
             }
+        };
+        self.advance(n);
+        Ok(n)
+    }
+}
+
+/// An element of a ring buffer created by [`RingBuffer::init_padded`],
+/// padded to its own cache line.
+pub struct Padded<T>(CachePadded<T>);
+
+impl<T> Padded<T> {
+    fn new(t: T) -> Self {
+        Padded(CachePadded::new(t))
+    }
+
+    fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+/// The writer of a ring buffer created by [`RingBuffer::init_padded`].
+pub type PaddedWriter<T, const N: usize> = MapWriter<T, Padded<T>, fn(T) -> Padded<T>, N>;
+
+/// The reader of a ring buffer created by [`RingBuffer::init_padded`].
+pub type PaddedReader<T, const N: usize> = MapReader<Padded<T>, T, fn(Padded<T>) -> T, N>;
+
+/// A writer applying a transformation to the elements before pushing them,
+/// created by [`RingBufferWriter::with`].
+pub struct MapWriter<U, T, F, const N: usize> {
+    inner: RingBufferWriter<T, N>,
+    f: F,
+    _u: PhantomData<fn(U)>,
+}
+
+impl<U, T, F: FnMut(U) -> T, const N: usize> MapWriter<U, T, F, N> {
+    /// Transforms and pushes an element, honoring the [`FullPolicy`] of the
+    /// underlying writer. The transformation is applied only if the element
+    /// is going to be stored, so that the untransformed element can be given
+    /// back when the buffer is full.
+    #[inline]
+    pub fn push(&mut self, u: U) -> Option<U> {
+        if self.inner.full_policy == FullPolicy::Reject && self.inner.is_full() {
+            return Some(u);
+        }
+        let f = &mut self.f;
+        let res = self.inner.push_with(|| f(u));
+        debug_assert!(res.is_none());
+        None
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> RingBufferWriter<T, N> {
+        self.inner
+    }
+}
+
+pub struct RingBufferReader<T, const N: usize> {
+    inner: Arc<RingBuffer<T, N>>,
+    local_idx_r: Index,
+    cached_idx_w: Index,
+    #[cfg(feature = "std")]
+    arrivals: blocking::Arrivals,
+    low_watermark: Option<Watermark>,
+    #[cfg(feature = "replay-log")]
+    log: replay::ReplayLog,
+    #[cfg(any(test, feature = "test-util"))]
+    hook: Option<SyncHook>,
+}
+
+impl<T, const N: usize> RingBufferReader<T, N> {
+    fn new(inner: Arc<RingBuffer<T, N>>) -> Self {
+        Self {
+            inner,
+            local_idx_r: 0,
+            cached_idx_w: 0,
+            #[cfg(feature = "std")]
+            arrivals: blocking::Arrivals::new(),
+            low_watermark: None,
+            #[cfg(feature = "replay-log")]
+            log: replay::ReplayLog::new(),
+            #[cfg(any(test, feature = "test-util"))]
+            hook: None,
+        }
+    }
+
+    /// Returns a read-only [`RingDescriptor`] of this ring buffer.
+    pub fn descriptor(&self) -> RingDescriptor<T, N> {
+        RingDescriptor {
+            inner: self.inner.clone(),
+        }
+    }
+
+    /// Returns the shared read index, for external code consuming the slots
+    /// on behalf of the reader.
+    ///
+    /// This mirrors [`RingBufferWriter::raw_write_index`], whose description
+    /// of the index applies here as well.
+    ///
+    /// # Safety
+    /// External code advancing the index from `r` to `r + k` must:
+    /// - only read the slots of indexes `r..r + k`, which must all be
+    ///   available, i.e. `r + k` must not exceed the write index `w` loaded
+    ///   with `Acquire`;
+    /// - move the elements out of them (or drop them) before storing `r + k`
+    ///   with `Release` ordering, which hands the slots back to the writer;
+    /// - be the only one advancing the index, i.e. this reader must not pull
+    ///   anymore since its own copy of the index is then stale.
+    pub unsafe fn raw_read_index(&self) -> &AtomicIndex {
+        &self.inner.idx_r
+    }
+
+    /// Returns `false` once the writer has been dropped, after which no more
+    /// elements are pushed. Elements pushed before might still be available.
+    pub fn is_writer_alive(&self) -> bool {
+        !self.inner.disconnected.load(Ordering::Acquire)
+    }
+
+    /// Pulls an element like [`pull`](Self::pull), telling apart an empty
+    /// ring buffer from one whose writer has been dropped, so that retry
+    /// loops can stop once every element pushed has been pulled.
+    pub fn try_pull(&mut self) -> Result<T, PullError> {
+        if let Some(t) = self.pull() {
+            return Ok(t);
+        }
+        if self.is_writer_alive() {
+            return Err(PullError::Empty);
+        }
+        // The writer might have pushed more elements before being dropped
+        self.pull().ok_or(PullError::Disconnected)
+    }
+
+    /// Pulls an element like [`pull`](Self::pull), and reports whether it
+    /// made the ring buffer non-full, so that a writer sleeping while the
+    /// buffer is full is woken up only when needed.
+    ///
+    /// This mirrors [`RingBufferWriter::push_signal`]: the read index is
+    /// published before the write index is loaded, with a sequentially
+    /// consistent fence in between, and a writer going to sleep must check
+    /// that the buffer is still full after announcing itself.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::{PullSignal, RingBuffer};
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    /// assert!(tx.push(0).is_none());
+    /// assert!(tx.push(1).is_none());
+    /// assert_eq!(rx.pull_signal(), PullSignal::RemovedWake(0));
+    /// assert_eq!(rx.pull_signal(), PullSignal::RemovedNoWake(1));
+    /// assert_eq!(rx.pull_signal(), PullSignal::Empty);
+    /// ```
+    pub fn pull_signal(&mut self) -> PullSignal<T> {
+        let idx_r = self.local_idx_r;
+        let Some(t) = self.pull() else {
+            return PullSignal::Empty;
+        };
+        atomic::fence(Ordering::SeqCst);
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        if distance(idx_r, self.cached_idx_w) == N {
+            PullSignal::RemovedWake(t)
+        } else {
+            PullSignal::RemovedNoWake(t)
+        }
+    }
+
+    /// Sets a callback fired by the reader when the occupancy drops to `level`
+    /// after having been above it, e.g. to resume a paused producer.
+    ///
+    /// The callback fires once per crossing rather than on every pull while
+    /// the occupancy stays at or below `level`. While it does, every pull
+    /// loads the write index to detect when the occupancy rises again.
+    pub fn on_low_watermark(&mut self, level: usize, f: impl FnMut() + Send + 'static) {
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        self.low_watermark = Some(Watermark {
+            level,
+            armed: distance(self.local_idx_r, self.cached_idx_w) > level,
+            f: Box::new(f),
+        });
+    }
+
+    #[inline]
+    fn check_low_watermark(&mut self) {
+        if let Some(wm) = self.low_watermark.as_mut() {
+            // The cached write index makes this a lower bound of the occupancy
+            let mut len = distance(self.local_idx_r, self.cached_idx_w);
+            if len <= wm.level {
+                self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+                len = distance(self.local_idx_r, self.cached_idx_w);
+            }
+            wm.update(len <= wm.level);
+        }
+    }
+
+    /// Returns the most recent operations of the reader, oldest first, e.g. to
+    /// reconstruct the sequence of operations that led to a failure.
+    ///
+    /// Each operation publishing the read index records one event. At least
+    /// the last 512 events are kept. The operations of the writer are logged
+    /// separately, see [`RingBufferWriter::replay_log`].
+    #[cfg(feature = "replay-log")]
+    pub fn replay_log(&self) -> &[ReplayEvent] {
+        self.log.events()
+    }
+
+    /// Sets a callback invoked at the reader's synchronization points.
+    #[cfg(any(test, feature = "test-util"))]
+    pub fn set_sync_hook(&mut self, hook: impl FnMut(SyncPoint) + Send + 'static) {
+        self.hook = Some(Box::new(hook));
+    }
+
+    #[cfg(any(test, feature = "test-util"))]
+    #[inline]
+    fn sync_point(&mut self, point: SyncPoint) {
+        if let Some(hook) = self.hook.as_mut() {
+            hook(point);
+        }
+    }
+
+    /// Returns the number of times the reader found its cached write index
+    /// insufficient and had to load it from the shared state.
+    #[cfg(feature = "metrics")]
+    pub fn cache_miss_count(&self) -> usize {
+        self.inner.metrics.reader_cache_misses.get()
+    }
+
+    /// Returns the number of available elements.
+    ///
+    /// As with [`is_empty`](Self::is_empty), the write index is loaded only if
+    /// the buffer appears to be empty according to the cached one. Since the
+    /// writer runs concurrently, this is a lower bound.
+    pub fn len(&mut self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        distance(self.local_idx_r, self.cached_idx_w)
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    ///
+    /// The write index is loaded only if the buffer appears to be empty
+    /// according to the cached one.
+    #[inline]
+    pub fn is_empty(&mut self) -> bool {
+        // Check if the ring buffer is potentially empty
+        is_empty(self.local_idx_r, self.cached_idx_w) && self.refresh_is_empty()
+    }
+
+    /// Slow path of `is_empty()`, kept out of line so that the fast path
+    /// stays small in tight pull loops.
+    #[cold]
+    #[inline(never)]
+    fn refresh_is_empty(&mut self) -> bool {
+        // Update the write index
+        #[cfg(feature = "metrics")]
+        self.inner.metrics.reader_cache_misses.incr();
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::ReaderLoad);
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        // Check if the ring buffer is really empty
+        let empty = is_empty(self.local_idx_r, self.cached_idx_w);
+        // Catch up on a writer registration missed by the last pulls
+        #[cfg(feature = "async")]
+        if empty {
+            self.inner.writer_waker.wake();
+        }
+        empty
+    }
+
+    /// Returns `true` if the ring buffer is empty, without requiring exclusive
+    /// access to the reader, e.g. to report metrics.
+    ///
+    /// Unlike [`is_empty`](Self::is_empty), the write index is loaded every
+    /// time instead of being cached. This is meant for observation only: the
+    /// pull paths should rely on `is_empty()`.
+    pub fn is_empty_relaxed(&self) -> bool {
+        is_empty(self.local_idx_r, self.inner.idx_w.load(Ordering::Acquire))
+    }
+
+    #[inline]
+    pub fn pull(&mut self) -> Option<T> {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.reader_owner.enter("RingBufferReader");
+        if self.is_empty() {
+            return None;
         }
         // Remove the element from the ring buffer
         let t = unsafe {
@@ -168,9 +1986,1230 @@ impl<T, const N: usize> RingBufferReader<T, N> {
         };
         // Let's increment the counter and let it grow indefinitely
         // and potentially overflow resetting it to 0.
-        self.local_idx_r = self.local_idx_r.wrapping_add(1);
+        self.advance(1);
+
+        Some(t)
+    }
+
+    /// Pulls an element along with the index of the slot it was stored in
+    /// within the backing storage, e.g. to reason about cache line boundaries.
+    pub fn pull_at(&mut self) -> Option<(T, usize)> {
+        let idx = slot::<N>(self.local_idx_r);
+        self.pull().map(|t| (t, idx))
+    }
+
+    /// Pulls an element along with the time it spent in the ring buffer since
+    /// it was pushed.
+    #[cfg(feature = "timestamps")]
+    pub fn pull_timed(&mut self) -> Option<(T, std::time::Duration)> {
+        if self.is_empty() {
+            return None;
+        }
+        let stamp = unsafe { *self.inner.stamps[slot::<N>(self.local_idx_r)].get() };
+        self.pull().map(|t| (t, stamp.elapsed()))
+    }
+
+    /// Pulls an element, moving it directly into a new heap allocation.
+    ///
+    /// This is useful for large `T` that need to be handed over to another
+    /// thread: the slot is freed right away and the element can then be passed
+    /// around by pointer.
+    pub fn pull_boxed(&mut self) -> Option<Box<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut b = Box::<T>::new_uninit();
+        // Move the element from the ring buffer straight into the box
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.inner.get_mut(self.local_idx_r).as_ptr(),
+                b.as_mut_ptr(),
+                1,
+            )
+        };
+        self.advance(1);
+
+        Some(unsafe { b.assume_init() })
+    }
+
+    /// Returns references to the oldest and newest available elements, which
+    /// are the same if only one is available, or `None` if the buffer is empty.
+    pub fn peek_ends(&mut self) -> Option<(&T, &T)> {
+        let (a, b) = self.as_slices();
+        Some((a.first()?, b.last().or(a.last())?))
+    }
+
+    /// Returns an iterator pulling the elements available when it is created.
+    ///
+    /// The write index is loaded once, so the iterator terminates even if the
+    /// writer keeps pushing. Elements not yielded are left in the buffer.
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        let (a, b) = self.as_slices();
+        let len = a.len() + b.len();
+        Drain { reader: self, len }
+    }
+
+    /// Removes up to `max` of the newest available elements and returns them
+    /// newest first, leaving the older ones to be pulled in FIFO order, e.g.
+    /// for a stage preferring fresh data.
+    ///
+    /// Taking elements from the write end can't move the write index back,
+    /// since the writer owns it and may be pushing into the slots right after
+    /// it. Instead, the older elements are moved forward by the number of
+    /// elements taken, within the slots owned by the reader, and the read
+    /// index is advanced past the freed slots. This costs a move per element
+    /// left in the buffer, but never crosses the single-producer
+    /// single-consumer ownership boundary.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    /// for i in 0..5 {
+    ///     assert!(tx.push(i).is_none());
+    /// }
+    /// assert_eq!(rx.drain_lifo_batch(2), [4, 3]);
+    /// assert_eq!(rx.pull(), Some(0));
+    /// ```
+    pub fn drain_lifo_batch(&mut self, max: usize) -> Vec<T> {
+        let (a, b) = self.as_slices();
+        let len = a.len() + b.len();
+        let k = len.min(max);
+        let mut batch = Vec::with_capacity(k);
+        for i in (len - k..len).rev() {
+            let t = unsafe {
+                mem::replace(
+                    self.inner.get_mut(forward(self.local_idx_r, i)),
+                    MaybeUninit::uninit(),
+                )
+                .assume_init()
+            };
+            batch.push(t);
+        }
+        // Shift the older elements into the freed slots, the newest first so
+        // that none is overwritten before being moved.
+        for i in (0..len - k).rev() {
+            unsafe {
+                let t = self.inner.get(forward(self.local_idx_r, i)).as_ptr().read();
+                self.inner
+                    .get_mut(forward(self.local_idx_r, i + k))
+                    .write(t);
+            }
+        }
+        self.advance(k);
+        batch
+    }
+
+    /// Returns a view of the available elements in read order, without
+    /// consuming them, e.g. to dump the contents of the buffer while
+    /// debugging.
+    ///
+    /// The view reflects the moment the write index is loaded: the writer may
+    /// keep pushing elements afterwards, which are not part of it.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<u32, 4>::init();
+    /// assert!(tx.push(1).is_none());
+    /// assert!(tx.push(2).is_none());
+    /// assert_eq!(format!("{:?}", rx.snapshot()), "[1, 2]");
+    /// assert_eq!(rx.pull(), Some(1));
+    /// ```
+    pub fn snapshot(&mut self) -> Snapshot<'_, T> {
+        let (a, b) = self.as_slices();
+        Snapshot { a, b }
+    }
+
+    /// Replaces the next element to be pulled with `value` and returns it,
+    /// leaving it at the front of the ring buffer, or returns `None` and
+    /// drops `value` if the buffer is empty.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    /// assert_eq!(rx.replace_head(9), None);
+    /// assert!(tx.push(1).is_none());
+    /// assert_eq!(rx.replace_head(9), Some(1));
+    /// assert_eq!(rx.pull(), Some(9));
+    /// ```
+    pub fn replace_head(&mut self, value: T) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        // The element is owned by the reader until the read index is advanced
+        let head = unsafe { self.inner.get_mut(self.local_idx_r).assume_init_mut() };
+        Some(mem::replace(head, value))
+    }
+
+    /// Calls `f` with a reference to the next element to be pulled and a
+    /// [`FrontHandle`] through which `f` can decide to consume it, and returns
+    /// the result of `f`, or `None` if the buffer is empty.
+    ///
+    /// The element is consumed, i.e. pulled and dropped, only once `f`
+    /// returns, so that the reference stays valid for all of `f`.
+    pub fn with_front<R>(&mut self, f: impl FnOnce(&T, FrontHandle<'_>) -> R) -> Option<R> {
+        self.front(f).map(|(r, _)| r)
+    }
+
+    /// Like [`with_front`](Self::with_front), but also returns the element if
+    /// `f` consumed it.
+    fn front<R>(&mut self, f: impl FnOnce(&T, FrontHandle<'_>) -> R) -> Option<(R, Option<T>)> {
+        let consumed = Cell::new(false);
+        let r = f(
+            self.as_slices().0.first()?,
+            FrontHandle {
+                consumed: &consumed,
+            },
+        );
+        Some((r, if consumed.get() { self.pull() } else { None }))
+    }
+
+    /// Pulls the next element if `pred` returns `true` for it.
+    pub fn pop_if(&mut self, pred: impl FnOnce(&T) -> bool) -> Option<T> {
+        let ((), t) = self.front(|t, front| {
+            if pred(t) {
+                front.consume();
+            }
+        })?;
+        t
+    }
+
+    /// Returns the result of `f` on the next element to be pulled, without
+    /// consuming it, or `None` if the buffer is empty.
+    pub fn inspect_front<R>(&mut self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.with_front(|t, _| f(t))
+    }
+
+    /// Drops the next element to be pulled if `commit` returns `true` for it,
+    /// and returns whether it did, or `None` if the buffer is empty.
+    ///
+    /// Unlike [`pop_if`](Self::pop_if), the element is dropped in place
+    /// rather than moved out, e.g. once it has been processed through the
+    /// reference.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    /// assert!(tx.push("hello".to_string()).is_none());
+    /// let mut out = String::new();
+    /// assert_eq!(rx.peek_commit(|t| t.len() > 10), Some(false));
+    /// assert_eq!(rx.peek_commit(|t| { out.push_str(t); true }), Some(true));
+    /// assert_eq!(out, "hello");
+    /// assert_eq!(rx.peek_commit(|_| true), None);
+    /// ```
+    pub fn peek_commit(&mut self, commit: impl FnOnce(&T) -> bool) -> Option<bool> {
+        self.with_front(|t, front| {
+            let commit = commit(t);
+            if commit {
+                front.consume();
+            }
+            commit
+        })
+    }
+
+    /// Returns the largest contiguous run of available elements starting at
+    /// the read index, without consuming them, e.g. to parse them in place.
+    ///
+    /// The run stops at the end of the backing storage, so it is shorter than
+    /// [`len`](Self::len) when the data wraps around: the rest becomes
+    /// visible once the run has been [`consume`](Self::consume)d.
+    pub fn peek_slice(&mut self) -> &[T] {
+        self.as_slices().0
+    }
+
+    /// Returns the next `n` elements as two slices, the second one being
+    /// non-empty only when they wrap around the end of the buffer, without
+    /// consuming them, or `None` if fewer than `n` are available. This lets a
+    /// header spanning several elements be inspected before consuming it.
+    ///
+    /// Only elements published by the writer are exposed, never the slots it
+    /// may be writing to.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<u8, 8>::init();
+    /// assert_eq!(tx.push_slice(&[1, 2, 3]), 3);
+    /// assert_eq!(rx.peek_n(2), Some((&[1, 2][..], &[][..])));
+    /// assert_eq!(rx.peek_n(4), None);
+    /// ```
+    pub fn peek_n(&mut self, n: usize) -> Option<(&[T], &[T])> {
+        let (a, b) = self.as_slices();
+        if a.len() + b.len() < n {
+            return None;
+        }
+        let first = n.min(a.len());
+        Some((&a[..first], &b[..n - first]))
+    }
+
+    /// Drops the `n` oldest available elements, e.g. after they have been
+    /// processed through [`peek_slice`](Self::peek_slice).
+    ///
+    /// # Panics
+    /// Panics if fewer than `n` elements are available.
+    pub fn consume(&mut self, n: usize) {
+        let (a, b) = self.as_slices();
+        let len = a.len() + b.len();
+        assert!(
+            n <= len,
+            "RingBufferReader: cannot consume {n} elements, only {len} are available."
+        );
+        for i in 0..n {
+            unsafe {
+                self.inner
+                    .get_mut(forward(self.local_idx_r, i))
+                    .assume_init_drop()
+            };
+        }
+        #[cfg(feature = "replay-log")]
+        self.log
+            .record(ReplayOp::Skip, distance(0, self.local_idx_r), n);
+        self.publish(n);
+    }
+
+    /// Drops all the available elements, e.g. to resynchronize a protocol,
+    /// publishing the read index once.
+    pub fn clear(&mut self) {
+        let (a, b) = self.as_slices();
+        let n = a.len() + b.len();
+        for i in 0..n {
+            unsafe {
+                self.inner
+                    .get_mut(forward(self.local_idx_r, i))
+                    .assume_init_drop()
+            };
+        }
+        #[cfg(feature = "replay-log")]
+        self.log
+            .record(ReplayOp::Clear, distance(0, self.local_idx_r), n);
+        self.publish(n);
+    }
+
+    /// Pulls an element while staying at most `max_lag` elements behind the
+    /// writer: if more than `max_lag` elements are available, the oldest ones
+    /// are dropped first, e.g. for a live view that prefers fresh data over
+    /// complete data. A `max_lag` of 0 thus drops every available element and
+    /// returns `None`, even if the writer pushes new elements meanwhile.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+    /// for i in 0..10 {
+    ///     assert!(tx.push(i).is_none());
+    /// }
+    /// assert_eq!(rx.pull_bounded(3), Some(7));
+    /// assert_eq!(rx.len(), 2);
+    /// ```
+    pub fn pull_bounded(&mut self, max_lag: usize) -> Option<T> {
+        let (a, b) = self.as_slices();
+        let len = a.len() + b.len();
+        if len > max_lag {
+            self.consume(len - max_lag);
+        }
+        // Pulling would reload the write index and return a newer element
+        if max_lag == 0 {
+            return None;
+        }
+        self.pull()
+    }
+
+    /// Pulls and folds the available elements in order with `f`, starting
+    /// from `init`, until `f` fails.
+    ///
+    /// On failure, `f` gives back the accumulator, the error and the element
+    /// it failed on. The element is then put back at the front of the ring
+    /// buffer, so that it can be pulled again along with the following ones,
+    /// and the accumulator is returned with the error.
+    pub fn try_consume<B, E, F>(&mut self, init: B, mut f: F) -> Result<B, (B, E)>
+    where
+        F: FnMut(B, T) -> Result<B, (B, E, T)>,
+    {
+        /// Consumes the element at the read index when dropped, even if `f`
+        /// panics with it.
+        struct Consumed<'a, T, const N: usize>(&'a mut RingBufferReader<T, N>);
+
+        impl<T, const N: usize> Drop for Consumed<'_, T, N> {
+            fn drop(&mut self) {
+                self.0.advance(1);
+            }
+        }
+
+        let mut acc = init;
+        while !self.is_empty() {
+            // The slot is not released to the writer until the read index is
+            // advanced, so the element can be written back into it.
+            let t = unsafe { self.inner.get_mut(self.local_idx_r).assume_init_read() };
+            let consumed = Consumed(self);
+            match f(acc, t) {
+                Ok(b) => acc = b,
+                Err((b, e, t)) => {
+                    mem::forget(consumed);
+                    unsafe { self.inner.get_mut(self.local_idx_r).write(t) };
+                    return Err((b, e));
+                }
+            }
+        }
+        Ok(acc)
+    }
+
+    /// Folds the currently available elements in order with `f`, starting
+    /// from `init`, without consuming them.
+    pub fn peek_fold<B, F: FnMut(B, &T) -> B>(&mut self, init: B, f: F) -> B {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b).fold(init, f)
+    }
+
+    /// Returns every slot of the backing storage along with its index, and a
+    /// reference to the element it holds if any, for diagnostic purposes.
+    ///
+    /// A slot holds an element if it lies between the read index and the
+    /// freshly loaded write index.
+    #[cfg(any(feature = "test-util", feature = "debug-checks"))]
+    pub fn dump_slots(&self) -> impl Iterator<Item = (usize, Option<&T>)> {
+        let idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        let len = distance(self.local_idx_r, idx_w);
+        let start = slot::<N>(self.local_idx_r);
+        (0..N).map(move |i| {
+            // The live elements can't be pulled while the reader is borrowed
+            let live = (i + N - start) & (N - 1) < len;
+            (
+                i,
+                live.then(|| unsafe { self.inner.get(forward(0, i)).assume_init_ref() }),
+            )
+        })
+    }
+
+    /// Signals to the writer how far behind the reader is, e.g. for the
+    /// writer to skip optional data.
+    ///
+    /// This is only a hint, with no synchronization with the elements: the
+    /// meaning of `level` is up to the application, and the writer may
+    /// ignore it.
+    pub fn signal_backpressure(&mut self, level: u8) {
+        self.inner.backpressure.store(level, Ordering::Relaxed);
+    }
+
+    /// Returns the mask mapping a monotonic index, e.g. the one returned by
+    /// [`RingBufferWriter::consumer_position`], to its slot in the backing
+    /// storage: `slot = index & mask`. This is `N - 1`, `N` being a power of 2.
+    pub const fn mask(&self) -> usize {
+        N - 1
+    }
+
+    /// Returns how many times the reader went around the buffer, i.e. the
+    /// number of elements pulled so far divided by the capacity. Like the
+    /// read index, this wraps around.
+    pub fn laps(&self) -> usize {
+        distance(0, self.local_idx_r) >> RingBuffer::<T, N>::CAPACITY_LOG2
+    }
+
+    /// Returns the base-2 logarithm of the capacity.
+    pub const fn capacity_log2(&self) -> u32 {
+        RingBuffer::<T, N>::CAPACITY_LOG2
+    }
+
+    /// Returns the size in bytes of the storage of the elements, see
+    /// [`RingBuffer::CAPACITY_BYTES`].
+    pub const fn capacity_bytes(&self) -> usize {
+        RingBuffer::<T, N>::CAPACITY_BYTES
+    }
+
+    /// Returns the size in bytes allocated besides the storage of the
+    /// elements, see [`RingBuffer::OVERHEAD_BYTES`].
+    pub const fn overhead_bytes(&self) -> usize {
+        RingBuffer::<T, N>::OVERHEAD_BYTES
+    }
+
+    /// Returns a [`Peeker`] giving read-only access to the available elements
+    /// through a shared borrow of the reader.
+    pub fn peeker(&self) -> Peeker<'_, T, N> {
+        Peeker { reader: self }
+    }
+
+    /// Returns a [`PullView`] over up to `max` available elements, or `None`
+    /// if no element is available.
+    ///
+    /// The elements are borrowed in place and only the number declared with
+    /// [`PullView::set_consumed`] is consumed once the view is committed or
+    /// dropped.
+    pub fn pull_view(&mut self, max: usize) -> Option<PullView<'_, T, N>> {
+        let (a, b) = self.as_slices();
+        let len = (a.len() + b.len()).min(max);
+        (len > 0).then_some(PullView {
+            reader: self,
+            len,
+            consumed: 0,
+        })
+    }
+
+    /// Returns a [`PeekCursor`] walking through the currently available
+    /// elements without consuming them.
+    pub fn peekable(&mut self) -> PeekCursor<'_, T, N> {
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::ReaderLoad);
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        PeekCursor {
+            idx: self.local_idx_r,
+            reader: self,
+        }
+    }
+
+    /// Consumes the reader and returns the elements currently in the ring
+    /// buffer, in order.
+    ///
+    /// Elements pushed afterwards can no longer be pulled and are dropped
+    /// along with the ring buffer.
+    pub fn close_and_drain(mut self) -> Vec<T> {
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        let len = distance(self.local_idx_r, self.cached_idx_w);
+        let mut leftovers = Vec::with_capacity(len);
+        leftovers.extend(iter::from_fn(|| self.pull()).take(len));
+        leftovers
+    }
+
+    /// Pulls the elements up to and including the first one for which
+    /// `is_last` returns `true`, e.g. the last element of a variable-length
+    /// record.
+    ///
+    /// Returns `None` without consuming anything if no such element is
+    /// available yet, so that incomplete groups are never split.
+    pub fn pull_group<F: FnMut(&T) -> bool>(&mut self, mut is_last: F) -> Option<Vec<T>> {
+        let mut cursor = self.peekable();
+        let mut len = 0;
+        loop {
+            let t = cursor.peek_next()?;
+            len += 1;
+            if is_last(t) {
+                break;
+            }
+        }
+        let mut group = Vec::with_capacity(len);
+        group.extend(iter::from_fn(|| self.pull()).take(len));
+        Some(group)
+    }
+
+    /// Turns this reader into a [`MapReader`] applying `f` to each pulled
+    /// element.
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> MapReader<T, U, F, N> {
+        MapReader {
+            inner: self,
+            f,
+            _u: PhantomData,
+        }
+    }
+
+    /// Returns the elements currently available for reading as two slices,
+    /// the second one being non-empty only when the data wraps around the
+    /// end of the buffer.
+    #[inline]
+    fn as_slices(&mut self) -> (&[T], &[T]) {
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::ReaderLoad);
+        self.cached_idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        let len = distance(self.local_idx_r, self.cached_idx_w);
+        let start = slot::<N>(self.local_idx_r);
+        let first = len.min(N - start);
+        // The slots between the read and write indexes are initialized and
+        // won't be touched by the writer until the read index is advanced.
+        unsafe {
+            let ptr = self.inner.as_ptr();
+            (
+                slice::from_raw_parts(ptr.add(start), first),
+                slice::from_raw_parts(ptr, len - first),
+            )
+        }
+    }
+
+    /// Moves up to `max` available elements to `dst` and returns their number.
+    ///
+    /// # Safety
+    /// `dst` must be valid for writing `max` elements.
+    unsafe fn pull_into_ptr(&mut self, dst: *mut T, max: usize) -> usize {
+        let (a, b) = self.as_slices();
+        let n = max.min(a.len() + b.len());
+        let (a, b) = (&a[..n.min(a.len())], &b[..n - n.min(a.len())]);
+        ptr::copy_nonoverlapping(a.as_ptr(), dst, a.len());
+        ptr::copy_nonoverlapping(b.as_ptr(), dst.add(a.len()), b.len());
+
+        self.advance(n);
+        n
+    }
+
+    /// Advances the read index by `n` elements, which must have been moved
+    /// out of the ring buffer already.
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        #[cfg(feature = "replay-log")]
+        self.log
+            .record(ReplayOp::Pull, distance(0, self.local_idx_r), n);
+        self.publish(n);
+    }
+
+    /// Publishes the read index advanced by `n` elements, without recording
+    /// the operation.
+    #[inline]
+    fn publish(&mut self, n: usize) {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.reader_owner.enter("RingBufferReader");
+        self.local_idx_r = forward(self.local_idx_r, n);
         self.inner.idx_r.store(self.local_idx_r, Ordering::Release);
+        #[cfg(any(test, feature = "test-util"))]
+        self.sync_point(SyncPoint::ReaderStore);
+        self.check_low_watermark();
+        #[cfg(feature = "async")]
+        self.inner.writer_waker.wake_if_waiting();
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+impl<T, const N: usize> RingBufferReader<T, N> {
+    /// Moves as many available elements as fit into `out` and returns their
+    /// number.
+    pub fn pull_into_arrayvec<const M: usize>(
+        &mut self,
+        out: &mut arrayvec::ArrayVec<T, M>,
+    ) -> usize {
+        let len = out.len();
+        unsafe {
+            let n = self.pull_into_ptr(out.as_mut_ptr().add(len), out.remaining_capacity());
+            out.set_len(len + n);
+            n
+        }
+    }
+}
+
+/// A reader applying a transformation to the pulled elements, created by
+/// [`RingBufferReader::map`].
+///
+/// Only [`pull`](Self::pull) is supported: the transformation takes the
+/// elements by value, so they can't be peeked at.
+pub struct MapReader<T, U, F, const N: usize> {
+    inner: RingBufferReader<T, N>,
+    f: F,
+    _u: PhantomData<fn() -> U>,
+}
 
+impl<T, U, F: FnMut(T) -> U, const N: usize> MapReader<T, U, F, N> {
+    #[inline]
+    pub fn pull(&mut self) -> Option<U> {
+        self.inner.pull().map(&mut self.f)
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> RingBufferReader<T, N> {
+        self.inner
+    }
+}
+
+/// A non-consuming cursor over the elements of a [`RingBufferReader`],
+/// created by [`RingBufferReader::peekable`].
+///
+/// The cursor is bounded by the elements available when it was created.
+pub struct PeekCursor<'a, T, const N: usize> {
+    reader: &'a mut RingBufferReader<T, N>,
+    idx: Index,
+}
+
+impl<T, const N: usize> PeekCursor<'_, T, N> {
+    /// Advances the cursor and returns a reference to the next element.
+    pub fn peek_next(&mut self) -> Option<&T> {
+        if self.idx == self.reader.cached_idx_w {
+            return None;
+        }
+        let t = unsafe { self.reader.inner.get(self.idx).assume_init_ref() };
+        self.idx = self.idx.wrapping_add(1);
         Some(t)
     }
+
+    /// Drops all the elements the cursor walked past in place, and returns
+    /// their number.
+    pub fn consume(self) -> usize {
+        let n = distance(self.reader.local_idx_r, self.idx);
+        self.reader.consume(n);
+        n
+    }
+}
+
+/// An iterator pulling the elements available when it was created, created
+/// by [`RingBufferReader::drain`].
+pub struct Drain<'a, T, const N: usize> {
+    reader: &'a mut RingBufferReader<T, N>,
+    len: usize,
+}
+
+impl<T, const N: usize> Iterator for Drain<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // The element is available since the snapshot of the write index
+        self.reader.pull()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Drain<'_, T, N> {}
+
+/// The elements available when it was created, in read order, returned by
+/// [`RingBufferReader::snapshot`].
+pub struct Snapshot<'a, T> {
+    a: &'a [T],
+    b: &'a [T],
+}
+
+impl<'a, T> Snapshot<'a, T> {
+    /// Returns the number of elements in the snapshot.
+    pub fn len(&self) -> usize {
+        self.a.len() + self.b.len()
+    }
+
+    /// Returns `true` if the snapshot has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the elements in read order.
+    pub fn iter(&self) -> iter::Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.a.iter().chain(self.b)
+    }
+}
+
+impl<'a, T> IntoIterator for &Snapshot<'a, T> {
+    type Item = &'a T;
+    type IntoIter = iter::Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Snapshot<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Serializes the elements as a sequence, in read order.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Snapshot<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Lets the closure passed to [`RingBufferReader::with_front`] consume the
+/// element it inspects.
+pub struct FrontHandle<'a> {
+    consumed: &'a Cell<bool>,
+}
+
+impl FrontHandle<'_> {
+    /// Consumes the inspected element once the closure returns.
+    pub fn consume(self) {
+        self.consumed.set(true);
+    }
+}
+
+/// A borrowed view of available elements with an explicit commit, created by
+/// [`RingBufferReader::pull_view`].
+pub struct PullView<'a, T, const N: usize> {
+    reader: &'a mut RingBufferReader<T, N>,
+    len: usize,
+    consumed: usize,
+}
+
+impl<T, const N: usize> PullView<'_, T, N> {
+    /// Returns the elements of the view as two slices, the second one being
+    /// non-empty only when the elements wrap around the end of the buffer.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let start = slot::<N>(self.reader.local_idx_r);
+        let first = self.len.min(N - start);
+        // The elements of the view are initialized and can't be pulled while
+        // the reader is borrowed.
+        unsafe {
+            let ptr = self.reader.inner.as_ptr();
+            (
+                slice::from_raw_parts(ptr.add(start), first),
+                slice::from_raw_parts(ptr, self.len - first),
+            )
+        }
+    }
+
+    /// Returns the number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view holds no element, which never happens for
+    /// a view returned by [`RingBufferReader::pull_view`].
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Declares that the first `n` elements of the view have been consumed.
+    ///
+    /// # Panics
+    /// Panics if `n` exceeds the length of the view.
+    pub fn set_consumed(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "PullView: consumed {n} elements out of {}.",
+            self.len
+        );
+        self.consumed = n;
+    }
+
+    /// Drops the consumed elements and removes them from the ring buffer,
+    /// which is what dropping the view does as well.
+    pub fn commit(self) {}
+}
+
+impl<T, const N: usize> Drop for PullView<'_, T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.consumed {
+            let idx = forward(self.reader.local_idx_r, i);
+            unsafe { self.reader.inner.get_mut(idx).assume_init_drop() };
+        }
+        self.reader.advance(self.consumed);
+    }
+}
+
+/// A read-only view of the elements available to a [`RingBufferReader`],
+/// created by [`RingBufferReader::peeker`].
+///
+/// Unlike the reader, a peeker doesn't rely on the cached write index: every
+/// query loads it from the shared state, so it always sees the latest data.
+pub struct Peeker<'a, T, const N: usize> {
+    reader: &'a RingBufferReader<T, N>,
+}
+
+impl<T, const N: usize> Peeker<'_, T, N> {
+    /// Returns the number of elements available to the reader.
+    pub fn len(&self) -> usize {
+        let idx_w = self.reader.inner.idx_w.load(Ordering::Acquire);
+        distance(self.reader.local_idx_r, idx_w)
+    }
+
+    /// Returns `true` if no element is available to the reader.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a reference to the next element to be pulled.
+    pub fn peek(&self) -> Option<&T> {
+        self.peek_at(0)
+    }
+
+    /// Returns a reference to the `i`-th element to be pulled.
+    pub fn peek_at(&self, i: usize) -> Option<&T> {
+        if i >= self.len() {
+            return None;
+        }
+        // The element can't be pulled while the reader is borrowed, and the
+        // writer doesn't access it until it is pulled.
+        let idx = forward(self.reader.local_idx_r, i);
+        Some(unsafe { self.reader.inner.get(idx).assume_init_ref() })
+    }
+}
+
+impl<T: Copy, const N: usize> RingBufferReader<T, N> {
+    /// Copies as many available elements as fit into `dst` and returns their
+    /// number.
+    ///
+    /// The elements are copied with at most two memcpy's, and the read index
+    /// is published once for the whole slice.
+    pub fn pull_slice(&mut self, dst: &mut [T]) -> usize {
+        #[cfg(all(feature = "debug-checks", feature = "std", debug_assertions))]
+        let _owner = self.inner.reader_owner.enter("RingBufferReader");
+        unsafe { self.pull_into_ptr(dst.as_mut_ptr(), dst.len()) }
+    }
+
+    /// Copies available elements into `dst`, in a number that is a multiple of
+    /// `A`, and returns the number of elements copied.
+    ///
+    /// This lets a SIMD consumer of `A`-wide vectors process `dst` without any
+    /// scalar tail handling. If fewer than `A` elements are available (or fit
+    /// in `dst`) nothing is copied and `0` is returned: the remaining elements
+    /// can still be retrieved with [`pull`](Self::pull).
+    pub fn pull_aligned<const A: usize>(&mut self, dst: &mut [T]) -> usize {
+        const { assert!(A > 0, "The vector width must be non-zero.") };
+        let (a, b) = self.as_slices();
+        let n = (a.len() + b.len()).min(dst.len());
+        self.pull_slice(&mut dst[..n - n % A])
+    }
+
+    /// Pulls exactly `B` elements, or none if fewer are available.
+    ///
+    /// A batch larger than the capacity could never be pulled, so it is
+    /// rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (_tx, mut rx) = RingBuffer::<u8, 4>::init();
+    /// rx.pull_batch::<8>();
+    /// ```
+    pub fn pull_batch<const B: usize>(&mut self) -> Option<[T; B]> {
+        const { assert!(B <= N, "The batch size exceeds the capacity.") };
+        let (a, b) = self.as_slices();
+        if a.len() + b.len() < B {
+            return None;
+        }
+        let mut batch = [MaybeUninit::<T>::uninit(); B];
+        // SAFETY: MaybeUninit<T> has the same layout as T, and B elements are
+        // available so all of them are initialized.
+        unsafe {
+            self.pull_into_ptr(batch.as_mut_ptr() as *mut T, B);
+            Some(ptr::read(batch.as_ptr() as *const [T; B]))
+        }
+    }
+}
+
+impl<T: Copy + PartialEq, const N: usize> RingBufferReader<T, N> {
+    /// Copies available elements into `dst` up to and including the first
+    /// occurrence of `sentinel`, and returns the number of elements copied and
+    /// whether `sentinel` was found.
+    ///
+    /// If `dst` gets full or no more elements are available before `sentinel`
+    /// is found, the elements copied so far are returned along with `false`.
+    pub fn pull_slice_until(&mut self, dst: &mut [T], sentinel: T) -> (usize, bool) {
+        let (a, b) = self.as_slices();
+        let pos = a
+            .iter()
+            .chain(b)
+            .take(dst.len())
+            .position(|t| *t == sentinel);
+        let len = pos.map_or(dst.len(), |p| p + 1);
+        (self.pull_slice(&mut dst[..len]), pos.is_some())
+    }
+}
+
+impl<const N: usize> RingBufferReader<u8, N> {
+    /// Moves up to `dst.len()` bytes out of the ring buffer into `dst` while
+    /// feeding them into `hasher`, and returns the number of bytes moved.
+    ///
+    /// The bytes are fed in order with one [`Hasher::write`](core::hash::Hasher::write)
+    /// call per contiguous region, i.e. two calls when the data wraps around.
+    pub fn pull_checksummed(
+        &mut self,
+        dst: &mut [u8],
+        hasher: &mut impl core::hash::Hasher,
+    ) -> usize {
+        let (a, b) = self.as_slices();
+        let a = &a[..a.len().min(dst.len())];
+        let b = &b[..b.len().min(dst.len() - a.len())];
+        let (dst_a, dst_b) = dst.split_at_mut(a.len());
+        dst_a.copy_from_slice(a);
+        dst_b[..b.len()].copy_from_slice(b);
+        hasher.write(a);
+        if !b.is_empty() {
+            hasher.write(b);
+        }
+
+        let n = a.len() + b.len();
+        self.advance(n);
+        n
+    }
+
+    /// Feeds the bytes currently available for reading into `hasher` without
+    /// consuming them, e.g. to check their integrity before pulling them.
+    ///
+    /// Like [`pull_checksummed`](Self::pull_checksummed), the bytes are fed in
+    /// order with one [`Hasher::write`](core::hash::Hasher::write) call per
+    /// contiguous region.
+    pub fn peek_checksum(&mut self, hasher: &mut impl core::hash::Hasher) {
+        let (a, b) = self.as_slices();
+        hasher.write(a);
+        if !b.is_empty() {
+            hasher.write(b);
+        }
+    }
+
+    /// Writes the bytes currently available for reading to `w` and returns
+    /// their number.
+    ///
+    /// The bytes are written with [`write_vectored`](std::io::Write::write_vectored)
+    /// straight from the ring buffer, i.e. without copying them across the
+    /// wrap, and short writes are retried until all of them are written. The
+    /// bytes written before an error are consumed.
+    #[cfg(feature = "std")]
+    pub fn drain_to_writer<W: std::io::Write>(&mut self, w: &mut W) -> std::io::Result<usize> {
+        use std::io::{ErrorKind, IoSlice};
+
+        let (a, b) = self.as_slices();
+        let len = a.len() + b.len();
+        let mut total = 0;
+        while total < len {
+            // Leave out the bytes pushed in the meantime
+            let rem = len - total;
+            let (a, b) = self.as_slices();
+            let a = &a[..a.len().min(rem)];
+            let b = &b[..b.len().min(rem - a.len())];
+            let max = a.len() + b.len();
+            match w.write_vectored(&[IoSlice::new(a), IoSlice::new(b)]) {
+                Ok(0) => return Err(ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    // A writer reporting more bytes than offered must not
+                    // move the read index past the written bytes
+                    let n = n.min(max);
+                    self.advance(n);
+                    total += n;
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(total)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<const N: usize> RingBufferReader<u8, N> {
+    /// Returns the bytes currently available for reading reinterpreted as
+    /// two slices of `U`, without consuming them.
+    ///
+    /// The returned elements always start at the next byte to read, so that
+    /// consuming `size_of::<U>()` bytes per parsed element stays in sync. If
+    /// that byte is not aligned for `U`, both slices are empty. Otherwise
+    /// the first slice is the largest prefix of whole `U`s before the wrap,
+    /// and the second one continues it after the wrap only if the first
+    /// region has no trailing bytes and the start of the storage is aligned
+    /// for `U` too. Trailing bytes not forming a whole `U` are left out.
+    pub fn as_slices_of<U: bytemuck::Pod>(&mut self) -> (&[U], &[U]) {
+        /// Returns the whole `U`s at the start of `bytes`, if aligned, and
+        /// whether `bytes` has no trailing bytes.
+        fn cast<U: bytemuck::Pod>(bytes: &[u8]) -> Option<(&[U], bool)> {
+            if bytes.as_ptr().align_offset(mem::align_of::<U>()) != 0 {
+                return None;
+            }
+            let len = bytes.len() - bytes.len() % mem::size_of::<U>();
+            Some((bytemuck::cast_slice(&bytes[..len]), len == bytes.len()))
+        }
+
+        if mem::size_of::<U>() == 0 {
+            return (&[], &[]);
+        }
+        let (a, b) = self.as_slices();
+        match cast::<U>(a) {
+            Some((first, true)) if !first.is_empty() => match cast::<U>(b) {
+                Some((second, _)) => (first, second),
+                None => (first, &[]),
+            },
+            Some((first, _)) => (first, &[]),
+            None => (&[], &[]),
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for RingBufferReader<T, N> {
+    fn drop(&mut self) {
+        self.inner.disconnected.store(true, Ordering::Release);
+        #[cfg(feature = "async")]
+        self.inner.writer_waker.wake();
+    }
+}
+
+/// The reader of a ring buffer created with [`RingBuffer::init_lossy`].
+///
+/// Since the writer may evict the oldest element at any time, this reader
+/// does not cache the write index nor hand out references to the buffered
+/// elements.
+pub struct RingBufferLossyReader<T, const N: usize> {
+    inner: Arc<RingBuffer<T, N>>,
+}
+
+impl<T, const N: usize> RingBufferLossyReader<T, N> {
+    #[inline]
+    pub fn pull(&mut self) -> Option<T> {
+        self.inner.lock();
+        let idx_r = self.inner.idx_r.load(Ordering::Relaxed);
+        if idx_r == self.inner.idx_w.load(Ordering::Acquire) {
+            self.inner.unlock();
+            #[cfg(feature = "async")]
+            self.inner.writer_waker.wake();
+            return None;
+        }
+        let t =
+            unsafe { mem::replace(self.inner.get_mut(idx_r), MaybeUninit::uninit()).assume_init() };
+        self.inner
+            .idx_r
+            .store(idx_r.wrapping_add(1), Ordering::Release);
+        self.inner.unlock();
+        #[cfg(feature = "async")]
+        self.inner.writer_waker.wake_if_waiting();
+
+        Some(t)
+    }
+}
+
+impl<T, const N: usize> Drop for RingBufferLossyReader<T, N> {
+    fn drop(&mut self) {
+        self.inner.disconnected.store(true, Ordering::Release);
+        #[cfg(feature = "async")]
+        self.inner.writer_waker.wake();
+    }
+}
+
+/// A read-only view of a ring buffer, obtained from either handle.
+///
+/// A descriptor can only query the capacity and the occupancy of the ring
+/// buffer: it can neither push nor pull elements, and can therefore be shared
+/// freely, e.g. with third-party plugins.
+///
+/// ```compile_fail
+/// use ringbuffer_spsc::RingBuffer;
+///
+/// let (tx, _rx) = RingBuffer::<usize, 16>::init();
+/// tx.descriptor().push(0);
+/// ```
+///
+/// ```compile_fail
+/// use ringbuffer_spsc::RingBuffer;
+///
+/// let (_tx, rx) = RingBuffer::<usize, 16>::init();
+/// rx.descriptor().pull();
+/// ```
+pub struct RingDescriptor<T, const N: usize> {
+    inner: Arc<RingBuffer<T, N>>,
+}
+
+impl<T, const N: usize> Clone for RingDescriptor<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, const N: usize> RingDescriptor<T, N> {
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements in the ring buffer. Since the handles
+    /// operate concurrently, this is only a snapshot.
+    pub fn len(&self) -> usize {
+        // The read index is loaded first so that it can't overtake the write index.
+        let idx_r = self.inner.idx_r.load(Ordering::Acquire);
+        let idx_w = self.inner.idx_w.load(Ordering::Acquire);
+        distance(idx_r, idx_w).min(N)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+    use core::sync::atomic::AtomicUsize;
+
+    struct DropCounter<'a>(&'a AtomicUsize, String);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn check_wrap<const N: usize>() {
+        for offset in 1..=2 * N {
+            for occupancy in 0..=N {
+                let drops = AtomicUsize::new(0);
+                let mut created = 0;
+                let (mut tx, mut rx) =
+                    RingBuffer::<DropCounter, N>::init_at(Index::MAX - forward(0, offset));
+                let d = tx.descriptor();
+
+                // Go around the buffer a few times across the Index::MAX wrap
+                let mut pushed = 0;
+                for _ in 0..3 {
+                    while d.len() < occupancy {
+                        created += 1;
+                        assert!(tx.push(DropCounter(&drops, pushed.to_string())).is_none());
+                        pushed += 1;
+                    }
+                    assert_eq!(d.len(), occupancy);
+                    assert_eq!(d.is_empty(), occupancy == 0);
+                    assert_eq!(d.is_full(), occupancy == N);
+                    assert_eq!(rx.is_empty(), occupancy == 0);
+                    if occupancy == N {
+                        created += 1;
+                        assert!(tx.push(DropCounter(&drops, pushed.to_string())).is_some());
+                    }
+
+                    let mut cursor = rx.peekable();
+                    for i in 0..occupancy {
+                        let t = cursor.peek_next().unwrap();
+                        assert_eq!(t.1, (pushed - occupancy + i).to_string());
+                    }
+                    assert!(cursor.peek_next().is_none());
+
+                    if let Some(t) = rx.pull() {
+                        assert_eq!(t.1, (pushed - occupancy).to_string());
+                    }
+                    assert_eq!(d.len(), occupancy.saturating_sub(1));
+                    assert_eq!(drops.load(Ordering::Relaxed), created - d.len());
+                }
+
+                // Remaining elements are dropped exactly once
+                mem::drop((tx, rx, d));
+                assert_eq!(drops.load(Ordering::Relaxed), created);
+            }
+        }
+    }
+
+    #[test]
+    fn wrap_around_usize_max() {
+        check_wrap::<1>();
+        check_wrap::<2>();
+        check_wrap::<4>();
+        check_wrap::<16>();
+    }
+
+    #[test]
+    fn clear() {
+        let drops = AtomicUsize::new(0);
+        let (mut tx, mut rx) = RingBuffer::<DropCounter, 8>::init_at(Index::MAX - 3);
+        for i in 0..6 {
+            assert!(tx.push(DropCounter(&drops, i.to_string())).is_none());
+        }
+        assert_eq!(rx.pull().unwrap().1, "0");
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+
+        rx.clear();
+        assert_eq!(drops.load(Ordering::Relaxed), 6);
+        assert!(rx.is_empty());
+        assert!(tx.push(DropCounter(&drops, 6.to_string())).is_none());
+        assert_eq!(rx.pull().unwrap().1, "6");
+
+        mem::drop((tx, rx));
+        assert_eq!(drops.load(Ordering::Relaxed), 7);
+    }
+
+    #[cfg(feature = "u32-indices")]
+    #[test]
+    fn wrap_around_u32_max() {
+        // `wrap_around_usize_max` already crosses the u32 wrap in this case
+        assert_eq!(mem::size_of::<AtomicIndex>(), 4);
+        assert_eq!(forward(u32::MAX, 1), 0);
+        assert_eq!(distance(u32::MAX - 1, 1 << 30), (1 << 30) + 2);
+        assert_eq!(slot::<{ 1 << 31 }>(u32::MAX), (1 << 31) - 1);
+    }
 }
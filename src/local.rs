@@ -0,0 +1,122 @@
+use crate::{distance, drop_range, forward, wrap, CapacityError, Index};
+use alloc::boxed::Box;
+use core::{
+    cell::UnsafeCell,
+    mem::{self, MaybeUninit},
+};
+
+/// A ring buffer owned by a single thread, e.g. to use it as a plain FIFO
+/// queue, without the `Arc` and the atomic indexes that sharing it between a
+/// writer and a reader requires.
+///
+/// The capacity is chosen at runtime and must be a power of two. The slots
+/// are located and the elements dropped with the same logic as
+/// [`RingBuffer`](crate::RingBuffer).
+///
+/// ```
+/// use ringbuffer_spsc::LocalRingBuffer;
+///
+/// let mut rb = LocalRingBuffer::new(2);
+/// assert!(rb.push(0).is_none());
+/// assert!(rb.push(1).is_none());
+/// assert_eq!(rb.push(2), Some(2));
+/// assert_eq!(rb.peek(), Some(&0));
+/// assert_eq!(rb.pull(), Some(0));
+/// ```
+pub struct LocalRingBuffer<T> {
+    buffer: Box<[UnsafeCell<MaybeUninit<T>>]>,
+    idx_r: Index,
+    idx_w: Index,
+}
+
+impl<T> LocalRingBuffer<T> {
+    /// Creates a ring buffer of the given capacity.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is not a valid capacity, see [`CapacityError`].
+    pub fn new(capacity: usize) -> Self {
+        if let Err(e) = CapacityError::check(capacity) {
+            panic!("LocalRingBuffer capacity {capacity} is invalid: {e}.");
+        }
+        Self {
+            buffer: core::iter::repeat_with(|| UnsafeCell::new(MaybeUninit::uninit()))
+                .take(capacity)
+                .collect(),
+            idx_r: 0,
+            idx_w: 0,
+        }
+    }
+
+    /// Returns the capacity of the ring buffer.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the number of elements in the ring buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        distance(self.idx_r, self.idx_w)
+    }
+
+    /// Returns `true` if the ring buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.idx_r == self.idx_w
+    }
+
+    /// Returns `true` if the ring buffer is full.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Pushes an element, or gives it back if the ring buffer is full.
+    #[inline]
+    pub fn push(&mut self, t: T) -> Option<T> {
+        if self.is_full() {
+            return Some(t);
+        }
+        self.slot_mut(self.idx_w).write(t);
+        self.idx_w = forward(self.idx_w, 1);
+
+        None
+    }
+
+    /// Pulls the oldest element, or returns `None` if the ring buffer is
+    /// empty.
+    #[inline]
+    pub fn pull(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let t =
+            unsafe { mem::replace(self.slot_mut(self.idx_r), MaybeUninit::uninit()).assume_init() };
+        self.idx_r = forward(self.idx_r, 1);
+
+        Some(t)
+    }
+
+    /// Returns a reference to the oldest element, or `None` if the ring
+    /// buffer is empty.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let slot = &self.buffer[wrap(self.idx_r, self.capacity())];
+        Some(unsafe { (*slot.get()).assume_init_ref() })
+    }
+
+    #[inline]
+    fn slot_mut(&mut self, idx: Index) -> &mut MaybeUninit<T> {
+        let capacity = self.capacity();
+        self.buffer[wrap(idx, capacity)].get_mut()
+    }
+}
+
+impl<T> Drop for LocalRingBuffer<T> {
+    fn drop(&mut self) {
+        unsafe { drop_range(&self.buffer, self.idx_r, self.idx_w) };
+    }
+}
@@ -0,0 +1,131 @@
+use crate::grow::{segment, Handoff, Segment};
+use crate::{AnyWriter, CapacityError, RingBuffer};
+use alloc::sync::Arc;
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a ring buffer of initial capacity `N` whose writer can later
+    /// migrate to ring buffers of other capacities, e.g. to resize it without
+    /// stopping either side.
+    ///
+    /// On [`begin_migration`](MigratingWriter::begin_migration), the writer
+    /// moves on to a new ring buffer of the requested capacity, hands its
+    /// reader over to the reader and drops its handle to the previous ring
+    /// buffer. The reader keeps draining the previous ring buffer and
+    /// switches to the new one only once it is empty. Since the writer never
+    /// pushes into a ring buffer after handing it off, no element is lost or
+    /// reordered: every element pushed before a migration is pulled before
+    /// any element pushed after it. The writer may migrate again before the
+    /// reader has caught up, in which case the reader drains the ring buffers
+    /// one after the other.
+    ///
+    /// The synchronization cost is the same as for
+    /// [`init_growing`](Self::init_growing): an extra atomic load for the
+    /// reader whenever its current ring buffer is empty, and an allocation
+    /// per migration. Drained ring buffers are freed by the reader.
+    ///
+    /// # Panics
+    /// Panics if `N` is zero.
+    ///
+    /// ```
+    /// use ringbuffer_spsc::RingBuffer;
+    ///
+    /// let (mut tx, mut rx) = RingBuffer::<usize, 2>::init_migrating();
+    /// assert!(tx.push(0).is_none());
+    /// assert!(tx.push(1).is_none());
+    /// assert_eq!(tx.push(2), Some(2));
+    /// tx.begin_migration(8).unwrap();
+    /// assert!(tx.push(2).is_none());
+    /// assert_eq!(rx.pull(), Some(0));
+    /// assert_eq!(rx.pull(), Some(1));
+    /// assert_eq!(rx.pull(), Some(2));
+    /// ```
+    pub fn init_migrating() -> (MigratingWriter<T>, MigratingReader<T>) {
+        let (tx, segment) = match segment(N) {
+            Ok(handles) => handles,
+            Err(e) => panic!("RingBuffer capacity {N} is invalid: {e}."),
+        };
+        (
+            MigratingWriter {
+                tx,
+                handoff: segment.handoff.clone(),
+                pushed: 0,
+            },
+            MigratingReader { segment },
+        )
+    }
+}
+
+/// The handoff point of a migration, returned by
+/// [`MigratingWriter::begin_migration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Migration {
+    position: usize,
+}
+
+impl Migration {
+    /// Returns the number of elements pushed before the handoff, i.e. the
+    /// number of elements the reader pulls before switching to the ring
+    /// buffer of this migration.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+pub struct MigratingWriter<T> {
+    tx: AnyWriter<T>,
+    handoff: Arc<Handoff<T>>,
+    // The number of elements pushed so far, across migrations.
+    pushed: usize,
+}
+
+impl<T> MigratingWriter<T> {
+    /// Pushes an element into the current ring buffer, returning it back if
+    /// the ring buffer is full.
+    pub fn push(&mut self, t: T) -> Option<T> {
+        let t = self.tx.push(t);
+        if t.is_none() {
+            self.pushed = self.pushed.wrapping_add(1);
+        }
+        t
+    }
+
+    /// Moves the writer on to a new ring buffer of capacity `new_capacity`,
+    /// returning the handoff point.
+    ///
+    /// The handle to the previous ring buffer is dropped, so that it is
+    /// freed as soon as the reader has drained it. Fails without migrating if
+    /// `new_capacity` is not a valid capacity for a
+    /// [`RingBufferAny`](crate::RingBufferAny).
+    pub fn begin_migration(&mut self, new_capacity: usize) -> Result<Migration, CapacityError> {
+        let (tx, segment) = segment(new_capacity)?;
+        let handoff = segment.handoff.clone();
+        // No element is pushed into the current ring buffer after this point
+        self.handoff.publish(segment);
+        (self.tx, self.handoff) = (tx, handoff);
+        Ok(Migration {
+            position: self.pushed,
+        })
+    }
+
+    /// Returns the capacity of the ring buffer currently pushed into.
+    pub fn capacity(&self) -> usize {
+        self.tx.capacity()
+    }
+}
+
+pub struct MigratingReader<T> {
+    segment: Segment<T>,
+}
+
+impl<T> MigratingReader<T> {
+    /// Pulls an element, switching to the next ring buffer once the writer
+    /// has migrated and the current one is drained.
+    pub fn pull(&mut self) -> Option<T> {
+        self.segment.pull()
+    }
+
+    /// Returns the capacity of the ring buffer currently pulled from.
+    pub fn capacity(&self) -> usize {
+        self.segment.capacity()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{storage::Storage, CapacityError, RingBuffer, RingBufferReader, RingBufferWriter};
+use std::{alloc::Layout, format, io, mem, vec};
+
+const MPOL_BIND: libc::c_int = 2;
+const MPOL_MF_STRICT: libc::c_uint = 1 << 0;
+const MPOL_MF_MOVE: libc::c_uint = 1 << 1;
+// The largest number of nodes supported by Linux, `1 << CONFIG_NODES_SHIFT`.
+const MAX_NODES: u32 = 1 << 10;
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a ring buffer whose backing storage is bound to the given NUMA
+    /// node, e.g. the node of the CPU running the consumer.
+    ///
+    /// The storage is allocated on its own pages, rounded up to a whole number
+    /// of pages, and bound with `mbind(2)`, so that the binding doesn't affect
+    /// any neighbouring allocation. Zero-sized elements need no storage, in
+    /// which case nothing is bound. An error is returned if the system does
+    /// not support NUMA or if `node` is not a valid node. Nodes beyond the
+    /// 1024 supported by Linux are rejected with
+    /// [`InvalidInput`](io::ErrorKind::InvalidInput) before allocating.
+    ///
+    /// Like for every other constructor, the capacity is the const generic
+    /// `N`, which keeps the slot of an index a mask known at compile time;
+    /// only the node, which is usually discovered at runtime, is a parameter.
+    ///
+    /// # Panics
+    /// Panics if `N` is not a valid capacity, see [`CapacityError`].
+    pub fn init_on_node(node: u32) -> io::Result<(RingBufferWriter<T, N>, RingBufferReader<T, N>)> {
+        if let Err(e) = CapacityError::check(N) {
+            panic!("RingBuffer capacity {N} is invalid: {e}.");
+        }
+        if node >= MAX_NODES {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("NUMA node {node} exceeds the maximum of {MAX_NODES} nodes"),
+            ));
+        }
+        let page = unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize;
+        let size = mem::size_of::<T>() * N;
+        let layout =
+            Layout::from_size_align(size.next_multiple_of(page), page.max(mem::align_of::<T>()))
+                .map_err(|_| io::Error::from(io::ErrorKind::OutOfMemory))?;
+        let storage = Storage::with_layout(layout);
+
+        let (addr, len) = storage.as_bytes();
+        if len != 0 {
+            let bits = libc::c_ulong::BITS as usize;
+            let mut mask = vec![0 as libc::c_ulong; node as usize / bits + 1];
+            mask[node as usize / bits] |= 1 << (node as usize % bits);
+
+            let res = unsafe {
+                libc::syscall(
+                    libc::SYS_mbind,
+                    addr,
+                    len,
+                    MPOL_BIND,
+                    mask.as_ptr(),
+                    mask.len() * bits + 1,
+                    MPOL_MF_STRICT | MPOL_MF_MOVE,
+                )
+            };
+            if res != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+
+        let rb = Self::alloc_in(storage, false);
+        Ok((RingBufferWriter::new(rb.clone()), RingBufferReader::new(rb)))
+    }
+}
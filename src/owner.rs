@@ -0,0 +1,58 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The thread currently operating a handle, to catch two threads operating
+/// the same handle at once.
+pub(crate) struct Owner {
+    // The address of a thread-local, unique among the live threads, or 0 if
+    // no thread is operating the handle.
+    thread: AtomicUsize,
+}
+
+impl Owner {
+    pub(crate) const fn new() -> Self {
+        Self {
+            thread: AtomicUsize::new(0),
+        }
+    }
+
+    fn current() -> usize {
+        std::thread_local!(static ID: u8 = const { 0 });
+        ID.with(|id| id as *const u8 as usize)
+    }
+
+    /// Marks the calling thread as operating the handle until the returned
+    /// guard is dropped.
+    ///
+    /// # Panics
+    /// Panics if another thread is operating the handle.
+    #[track_caller]
+    pub(crate) fn enter(&self, handle: &str) -> Entered {
+        let me = Self::current();
+        match self
+            .thread
+            .compare_exchange(0, me, Ordering::Acquire, Ordering::Relaxed)
+        {
+            Ok(_) => Entered(self),
+            // Nested call, e.g. a push path advancing the index
+            Err(owner) if owner == me => Entered(core::ptr::null()),
+            Err(_) => panic!(
+                "{handle} used from two threads at once, which violates the \
+                 single-producer single-consumer contract."
+            ),
+        }
+    }
+}
+
+/// Releases the handle when dropped, see [`Owner::enter`].
+///
+/// The guard points to the shared state of the ring buffer, which the handle
+/// keeps alive while operating.
+pub(crate) struct Entered(*const Owner);
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        if let Some(owner) = unsafe { self.0.as_ref() } {
+            owner.thread.store(0, Ordering::Release);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{RingBuffer, RingBufferReader, RingBufferWriter};
+
+impl<T, const N: usize> RingBuffer<T, N> {
+    /// Creates a pair of ring buffers circulating the objects of `pool`, e.g.
+    /// preallocated buffers, between a producer and a consumer: the producer
+    /// takes empty objects, fills them and submits them to the consumer,
+    /// which gives them back once processed. No allocation happens after
+    /// the pool has been built.
+    ///
+    /// # Panics
+    /// Panics if `pool` yields more than `N` objects.
+    pub fn init_recycling(
+        pool: impl IntoIterator<Item = T>,
+    ) -> (RecyclingProducer<T, N>, RecyclingConsumer<T, N>) {
+        let (full_tx, full_rx) = Self::init();
+        let (mut empty_tx, empty_rx) = Self::init();
+        for t in pool {
+            assert!(
+                empty_tx.push(t).is_none(),
+                "The pool can't hold more than {N} objects."
+            );
+        }
+        (
+            RecyclingProducer {
+                full: full_tx,
+                empty: empty_rx,
+            },
+            RecyclingConsumer {
+                full: full_rx,
+                empty: empty_tx,
+            },
+        )
+    }
+}
+
+pub struct RecyclingProducer<T, const N: usize> {
+    full: RingBufferWriter<T, N>,
+    empty: RingBufferReader<T, N>,
+}
+
+impl<T, const N: usize> RecyclingProducer<T, N> {
+    /// Takes an object given back by the consumer, if any.
+    pub fn get_empty(&mut self) -> Option<T> {
+        self.empty.pull()
+    }
+
+    /// Submits an object to the consumer. The object is given back if the
+    /// consumer already holds `N` objects, which can only happen if objects
+    /// not coming from the pool are submitted.
+    pub fn submit_full(&mut self, t: T) -> Option<T> {
+        self.full.push(t)
+    }
+}
+
+pub struct RecyclingConsumer<T, const N: usize> {
+    full: RingBufferReader<T, N>,
+    empty: RingBufferWriter<T, N>,
+}
+
+impl<T, const N: usize> RecyclingConsumer<T, N> {
+    /// Takes an object submitted by the producer, if any.
+    pub fn get_full(&mut self) -> Option<T> {
+        self.full.pull()
+    }
+
+    /// Gives an object back to the producer. The object is given back if the
+    /// producer already holds `N` objects, which can only happen if objects
+    /// not coming from the pool are returned.
+    pub fn return_empty(&mut self, t: T) -> Option<T> {
+        self.empty.push(t)
+    }
+}
@@ -0,0 +1,52 @@
+use alloc::vec::Vec;
+
+/// An operation recorded in a replay log, see
+/// [`RingBufferReader::replay_log`](crate::RingBufferReader::replay_log).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplayOp {
+    /// Elements were pushed.
+    Push,
+    /// Elements were pulled.
+    Pull,
+    /// Elements were dropped without being handed out, e.g. with
+    /// [`RingBufferReader::consume`](crate::RingBufferReader::consume).
+    Skip,
+    /// The buffer was cleared.
+    Clear,
+}
+
+/// An entry of a replay log: `len` elements starting at the monotonic index
+/// `idx` went through `op`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReplayEvent {
+    pub op: ReplayOp,
+    pub idx: usize,
+    pub len: usize,
+}
+
+/// The operations of a handle, bounded to the most recent ones.
+pub(crate) struct ReplayLog {
+    events: Vec<ReplayEvent>,
+}
+
+impl ReplayLog {
+    const CAPACITY: usize = 1024;
+
+    pub(crate) fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    #[inline]
+    pub(crate) fn record(&mut self, op: ReplayOp, idx: usize, len: usize) {
+        if self.events.len() == Self::CAPACITY {
+            // Dropping half of the log at once keeps recording amortized O(1)
+            // while the events stay contiguous.
+            self.events.drain(..Self::CAPACITY / 2);
+        }
+        self.events.push(ReplayEvent { op, idx, len });
+    }
+
+    pub(crate) fn events(&self) -> &[ReplayEvent] {
+        &self.events
+    }
+}
@@ -0,0 +1,36 @@
+use crate::RingBufferReader;
+
+/// Pulls from several readers in turn, e.g. for a consumer draining multiple
+/// ring buffers.
+///
+/// Readers are polled in a round-robin fashion, starting after the one last
+/// pulled from, so that a busy ring buffer can't starve the others.
+#[derive(Debug, Default)]
+pub struct Selector {
+    start: usize,
+}
+
+impl Selector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pulls an element from the first non-empty reader, starting from the
+    /// one after the reader last pulled from, and returns it along with the
+    /// index of its reader in `readers`. Returns `None` if all the readers
+    /// are empty.
+    pub fn select_pull<T, const N: usize>(
+        &mut self,
+        readers: &mut [&mut RingBufferReader<T, N>],
+    ) -> Option<(usize, T)> {
+        if readers.is_empty() {
+            return None;
+        }
+        let start = self.start % readers.len();
+        let (i, t) = (start..readers.len())
+            .chain(0..start)
+            .find_map(|i| readers[i].pull().map(|t| (i, t)))?;
+        self.start = i + 1;
+        Some((i, t))
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{PushError, RingBufferWriter};
+use core::{
+    future,
+    pin::Pin,
+    sync::atomic::{self, AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+use std::sync::Mutex;
+
+/// The waker of a writer waiting for free space, woken up by the reader.
+pub(crate) struct WriterWaker {
+    // Whether a waker is registered, so that the reader only takes the mutex
+    // when the writer is actually waiting.
+    waiting: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl WriterWaker {
+    pub(crate) fn new() -> Self {
+        Self {
+            waiting: AtomicBool::new(false),
+            waker: Mutex::new(None),
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut slot = self.waker.lock().unwrap();
+        if !slot.as_ref().is_some_and(|w| w.will_wake(waker)) {
+            *slot = Some(waker.clone());
+        }
+        self.waiting.store(true, Ordering::Relaxed);
+        // Pairs with the fence in `wake()`: either the reader sees the flag
+        // set, or the writer sees the space freed by the reader.
+        atomic::fence(Ordering::SeqCst);
+    }
+
+    /// Wakes up the writer if the reader already sees it waiting, without a
+    /// fence.
+    ///
+    /// The writer only registers when the buffer is full, so this is meant
+    /// for the pulls freeing space. A pull racing with the registration may
+    /// miss the flag: the writer is then woken by a later pull, or at the
+    /// latest by `wake()` once the reader finds the buffer empty.
+    #[inline]
+    pub(crate) fn wake_if_waiting(&self) {
+        if self.waiting.load(Ordering::Relaxed) {
+            self.wake();
+        }
+    }
+
+    /// Wakes up the writer if it is waiting, after the reader freed space.
+    pub(crate) fn wake(&self) {
+        atomic::fence(Ordering::SeqCst);
+        if self.waiting.load(Ordering::Relaxed) && self.waiting.swap(false, Ordering::Relaxed) {
+            if let Some(waker) = self.waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> RingBufferWriter<T, N> {
+    /// Polls for free space in the ring buffer, following the contract of
+    /// `futures::Sink::poll_ready`.
+    ///
+    /// If the buffer is full, the waker of `cx` is woken up once the reader
+    /// frees a slot or is dropped.
+    pub fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if !self.is_full() {
+            return Poll::Ready(());
+        }
+        self.inner.writer_waker.register(cx.waker());
+        // The reader might have freed a slot before the waker was registered
+        if self.is_full() && self.is_reader_alive() {
+            Poll::Pending
+        } else {
+            Poll::Ready(())
+        }
+    }
+
+    /// Pushes an element after [`poll_ready`](Self::poll_ready) returned
+    /// `Ready`, following the contract of `futures::Sink::start_send`.
+    ///
+    /// The element is given back if the buffer is full, i.e. if `poll_ready`
+    /// was not called first. Pushed elements are visible to the reader right
+    /// away, so there is nothing to flush.
+    pub fn start_send(&mut self, t: T) -> Result<(), T> {
+        match self.push(t) {
+            None => Ok(()),
+            Some(t) => Err(t),
+        }
+    }
+
+    /// Pushes an element, waiting asynchronously while the ring buffer is
+    /// full.
+    ///
+    /// The element is given back if the reader has been dropped.
+    pub async fn push_async(&mut self, t: T) -> Result<(), T> {
+        future::poll_fn(|cx| self.poll_ready(cx)).await;
+        if !self.is_reader_alive() {
+            return Err(t);
+        }
+        self.start_send(t)
+    }
+}
+
+impl<T, const N: usize> futures_sink::Sink<T> for RingBufferWriter<T, N> {
+    /// Only returned by `start_send` if the buffer is full, i.e. if
+    /// `poll_ready` was not called first, or once the reader is gone.
+    type Error = PushError<T>;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        RingBufferWriter::poll_ready(self.get_mut(), cx).map(Ok)
+    }
+
+    fn start_send(self: Pin<&mut Self>, t: T) -> Result<(), Self::Error> {
+        self.get_mut().try_push(t)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
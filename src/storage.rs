@@ -0,0 +1,101 @@
+#[cfg(all(feature = "numa", target_os = "linux"))]
+use alloc::alloc::{alloc, handle_alloc_error};
+use alloc::{
+    alloc::{dealloc, Layout},
+    boxed::Box,
+};
+use core::{cell::UnsafeCell, mem::MaybeUninit, ops::Deref, ptr, ptr::NonNull};
+
+/// The slots of a ring buffer of capacity `N`, either allocated as a plain
+/// boxed array or with a custom layout, e.g. page-aligned to be bound to a
+/// NUMA node.
+pub(crate) struct Storage<T, const N: usize> {
+    ptr: NonNull<[UnsafeCell<MaybeUninit<T>>; N]>,
+    // The layout the slots were allocated with, `None` for a boxed array.
+    layout: Option<Layout>,
+}
+
+impl<T, const N: usize> Storage<T, N> {
+    pub(crate) fn from_box(slots: Box<[UnsafeCell<MaybeUninit<T>>; N]>) -> Self {
+        Self {
+            ptr: NonNull::from(Box::leak(slots)),
+            layout: None,
+        }
+    }
+
+    /// Allocates the slots with `layout`, which must be at least as large and
+    /// as aligned as the array of slots.
+    #[cfg(all(feature = "numa", target_os = "linux"))]
+    pub(crate) fn with_layout(layout: Layout) -> Self {
+        let array = Layout::new::<[UnsafeCell<MaybeUninit<T>>; N]>();
+        assert!(layout.size() >= array.size() && layout.align() >= array.align());
+        if layout.size() == 0 {
+            // Nothing to allocate, e.g. for zero-sized elements
+            return Self {
+                ptr: NonNull::dangling(),
+                layout: Some(layout),
+            };
+        }
+        let ptr = unsafe { alloc(layout) };
+        match NonNull::new(ptr) {
+            Some(ptr) => Self {
+                ptr: ptr.cast(),
+                layout: Some(layout),
+            },
+            None => handle_alloc_error(layout),
+        }
+    }
+
+    /// Returns the address and the length in bytes of the allocation.
+    #[cfg(all(feature = "numa", target_os = "linux"))]
+    pub(crate) fn as_bytes(&self) -> (*mut u8, usize) {
+        let len = match self.layout {
+            Some(layout) => layout.size(),
+            None => core::mem::size_of::<[UnsafeCell<MaybeUninit<T>>; N]>(),
+        };
+        (self.ptr.as_ptr().cast(), len)
+    }
+
+    /// Turns the slots into a boxed slice, copying them into a new box if they
+    /// were not allocated as one.
+    pub(crate) fn into_box(self) -> Box<[MaybeUninit<T>]> {
+        if self.layout.is_none() {
+            let slots = self.ptr.as_ptr() as *mut [MaybeUninit<T>; N];
+            core::mem::forget(self);
+            return unsafe { Box::from_raw(slots) };
+        }
+        let mut slots: Box<[MaybeUninit<T>]> = core::iter::repeat_with(MaybeUninit::uninit)
+            .take(N)
+            .collect();
+        unsafe {
+            ptr::copy_nonoverlapping(
+                self.ptr.as_ptr() as *const MaybeUninit<T>,
+                slots.as_mut_ptr(),
+                N,
+            )
+        };
+        slots
+    }
+}
+
+impl<T, const N: usize> Deref for Storage<T, N> {
+    type Target = [UnsafeCell<MaybeUninit<T>>; N];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T, const N: usize> Drop for Storage<T, N> {
+    fn drop(&mut self) {
+        // The slots are MaybeUninit, the ring buffer drops the elements left
+        match self.layout {
+            None => drop(unsafe { Box::from_raw(self.ptr.as_ptr()) }),
+            Some(layout) if layout.size() != 0 => unsafe {
+                dealloc(self.ptr.as_ptr().cast(), layout)
+            },
+            Some(_) => {}
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use ringbuffer_spsc::{CapacityError, RingBufferAny};
+
+#[test]
+fn any_capacity() {
+    assert_eq!(
+        RingBufferAny::<usize>::init(0).err(),
+        Some(CapacityError::Zero)
+    );
+
+    let (mut tx, mut rx) = RingBufferAny::<String>::init(5).unwrap();
+    assert_eq!(tx.capacity(), 5);
+    // Wrap the indexes around several times
+    for lap in 0..7 {
+        for i in 0..5 {
+            assert!(tx.push(format!("{lap}-{i}")).is_none());
+        }
+        assert!(tx.push(String::new()).is_some());
+        for i in 0..3 {
+            assert_eq!(rx.pull(), Some(format!("{lap}-{i}")));
+        }
+        for i in 3..5 {
+            assert_eq!(rx.pull(), Some(format!("{lap}-{i}")));
+        }
+        assert!(rx.pull().is_none());
+        assert!(tx.push(format!("{lap}")).is_none());
+        assert_eq!(rx.pull(), Some(format!("{lap}")));
+    }
+
+    // Elements left in the buffer are dropped along with it
+    for i in 0..3 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+}
+
+#[test]
+fn any_capacity_concurrent() {
+    const K: usize = 1_000_000;
+    let (mut tx, mut rx) = RingBufferAny::<usize>::init(12).unwrap();
+
+    let p = std::thread::spawn(move || {
+        let mut i = 0;
+        while i < K {
+            if tx.push(i).is_none() {
+                i += 1;
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let mut i = 0;
+    while i < K {
+        if let Some(t) = rx.pull() {
+            assert_eq!(t, i);
+            i += 1;
+        } else {
+            std::thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+}
+
+#[test]
+fn any_disconnected() {
+    let (tx, rx) = RingBufferAny::<usize>::init(3).unwrap();
+    assert!(tx.is_reader_alive());
+    assert!(rx.is_writer_alive());
+    drop(tx);
+    assert!(!rx.is_writer_alive());
+}
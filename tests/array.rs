@@ -0,0 +1,42 @@
+use ringbuffer_spsc::RingBufferArray;
+
+#[test]
+fn array_static() {
+    const N: usize = 100_000;
+    static RING: RingBufferArray<usize, 16> = RingBufferArray::new();
+
+    let (mut tx, mut rx) = RING.split().unwrap();
+    assert!(RING.split().is_none());
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            for i in 0..N {
+                while tx.push(i).is_some() {
+                    std::thread::yield_now();
+                }
+            }
+        });
+        for i in 0..N {
+            loop {
+                if let Some(t) = rx.pull() {
+                    assert_eq!(t, i);
+                    break;
+                }
+                std::thread::yield_now();
+            }
+        }
+    });
+}
+
+#[test]
+fn array_full_and_drop() {
+    let ring = RingBufferArray::<String, 4>::new();
+    let (mut tx, mut rx) = ring.split().unwrap();
+    for i in 0..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.is_full());
+    assert_eq!(tx.push("4".to_string()).as_deref(), Some("4"));
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+    assert!(tx.push("4".to_string()).is_none());
+    // The remaining elements are dropped along with the ring buffer
+}
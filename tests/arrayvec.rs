@@ -0,0 +1,30 @@
+#![cfg(feature = "arrayvec")]
+use arrayvec::ArrayVec;
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn pull_into_arrayvec() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 16>::init();
+    for i in 0..16 {
+        assert!(tx.push(i).is_none());
+    }
+
+    let mut out = ArrayVec::<u32, 8>::new();
+    assert_eq!(rx.pull_into_arrayvec(&mut out), 8);
+    assert_eq!(out.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(rx.pull_into_arrayvec(&mut out), 0);
+
+    // Non-Copy elements across the wrap
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    for i in 0..7 {
+        assert!(tx.push(i.to_string()).is_none());
+        if i < 3 {
+            assert!(rx.pull().is_some());
+        }
+    }
+    let mut out = ArrayVec::<String, 8>::new();
+    out.push("x".to_string());
+    assert_eq!(rx.pull_into_arrayvec(&mut out), 4);
+    assert_eq!(out.as_slice(), ["x", "3", "4", "5", "6"]);
+    assert!(rx.pull().is_none());
+}
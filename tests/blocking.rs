@@ -0,0 +1,233 @@
+#![cfg(feature = "std")]
+use ringbuffer_spsc::RingBuffer;
+use std::time::{Duration, Instant};
+
+#[test]
+fn blocking_send_recv() {
+    const N: usize = 100_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_blocking();
+
+    let p = std::thread::spawn(move || {
+        for i in 0..N {
+            tx.send(i);
+        }
+    });
+
+    for i in 0..N {
+        assert_eq!(rx.recv(), i);
+    }
+    p.join().unwrap();
+}
+
+#[test]
+fn blocking_recv_sleeps() {
+    const DELAY: Duration = Duration::from_millis(100);
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_blocking();
+
+    // The clock starts before spawning, so that a late start of the thread
+    // doesn't shorten the measured wait
+    let start = Instant::now();
+    let c = std::thread::spawn(move || {
+        let t = rx.recv();
+        (t, start.elapsed())
+    });
+
+    std::thread::sleep(DELAY);
+    tx.send(42);
+    let (t, elapsed) = c.join().unwrap();
+    assert_eq!(t, 42);
+    assert!(elapsed >= DELAY);
+}
+
+#[test]
+fn blocking_push_sleeps() {
+    const DELAY: Duration = Duration::from_millis(100);
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init_blocking();
+
+    for i in 0..4 {
+        tx.push_blocking(i);
+    }
+    let start = Instant::now();
+    let p = std::thread::spawn(move || {
+        tx.push_blocking(4);
+        start.elapsed()
+    });
+
+    std::thread::sleep(DELAY);
+    for i in 0..5 {
+        assert_eq!(rx.pull_blocking(), i);
+    }
+    assert!(p.join().unwrap() >= DELAY);
+}
+
+#[test]
+fn blocking_timeouts() {
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const SLACK: Duration = Duration::from_millis(500);
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init_blocking();
+
+    let start = Instant::now();
+    assert_eq!(rx.pull_timeout(TIMEOUT), None);
+    let elapsed = start.elapsed();
+    assert!(elapsed >= TIMEOUT && elapsed < TIMEOUT + SLACK);
+
+    assert_eq!(tx.push_timeout(0, TIMEOUT), Ok(()));
+    assert_eq!(tx.push_timeout(1, TIMEOUT), Ok(()));
+    let start = Instant::now();
+    assert_eq!(tx.push_timeout(2, TIMEOUT), Err(2));
+    let elapsed = start.elapsed();
+    assert!(elapsed >= TIMEOUT && elapsed < TIMEOUT + SLACK);
+
+    // Succeeds once the peer makes progress within the timeout
+    let c = std::thread::spawn(move || {
+        std::thread::sleep(TIMEOUT);
+        (rx.pull_blocking(), rx.pull_blocking(), rx.pull_blocking())
+    });
+    assert_eq!(tx.push_timeout(2, Duration::from_secs(10)), Ok(()));
+    assert_eq!(c.join().unwrap(), (0, 1, 2));
+}
+
+#[test]
+fn pull_until_shutdown() {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+    const DELAY: Duration = Duration::from_millis(50);
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+    let shutdown = Arc::new(AtomicBool::new(false));
+
+    assert!(tx.push(42).is_none());
+    assert_eq!(rx.pull_until(&shutdown), Some(42));
+
+    let s = shutdown.clone();
+    let start = Instant::now();
+    let c = std::thread::spawn(move || (rx.pull_until(&s), start.elapsed()));
+    std::thread::sleep(DELAY);
+    shutdown.store(true, Ordering::Release);
+    let (t, elapsed) = c.join().unwrap();
+    assert_eq!(t, None);
+    assert!(elapsed >= DELAY && elapsed < DELAY + Duration::from_millis(500));
+}
+
+#[test]
+fn push_until_shutdown() {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+    const DELAY: Duration = Duration::from_millis(50);
+    let (mut tx, rx) = RingBuffer::<String, 2>::init();
+    let shutdown = Arc::new(AtomicBool::new(false));
+
+    assert_eq!(tx.push_until("a".to_string(), &shutdown), None);
+    assert_eq!(tx.push_until("b".to_string(), &shutdown), None);
+
+    // Nobody pulls, the push only returns on shutdown
+    let s = shutdown.clone();
+    let start = Instant::now();
+    let p = std::thread::spawn(move || (tx.push_until("c".to_string(), &s), start.elapsed()));
+    std::thread::sleep(DELAY);
+    shutdown.store(true, Ordering::Release);
+    let (t, elapsed) = p.join().unwrap();
+    assert_eq!(t.as_deref(), Some("c"));
+    assert!(elapsed >= DELAY && elapsed < DELAY + Duration::from_millis(500));
+    drop(rx);
+}
+
+#[test]
+fn wait_contiguous() {
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+
+    // Move the indexes close to the end of the buffer
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+
+    // Not enough elements yet
+    assert!(tx.push(6).is_none());
+    let start = Instant::now();
+    assert!(rx.wait_contiguous(2, TIMEOUT).is_none());
+    assert!(start.elapsed() >= TIMEOUT);
+
+    // Enough elements in total but not contiguously
+    for i in 7..10 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.wait_contiguous(3, TIMEOUT), Some(&[6, 7][..]));
+    assert_eq!(rx.pull(), Some(6));
+    assert_eq!(rx.pull(), Some(7));
+    assert_eq!(rx.wait_contiguous(2, TIMEOUT), Some(&[8, 9][..]));
+
+    // Elements pushed concurrently
+    let p = std::thread::spawn(move || {
+        std::thread::sleep(TIMEOUT);
+        assert!(tx.push(10).is_none());
+    });
+    assert_eq!(rx.wait_contiguous(3, 20 * TIMEOUT), Some(&[8, 9, 10][..]));
+    p.join().unwrap();
+}
+
+#[test]
+fn pull_adaptive() {
+    const BURSTS: usize = 20;
+    const BURST: usize = 1_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+
+    let p = std::thread::spawn(move || {
+        for b in 0..BURSTS {
+            for i in 0..BURST {
+                let mut t = b * BURST + i;
+                while let Some(v) = tx.push(t) {
+                    t = v;
+                    std::hint::spin_loop();
+                }
+            }
+            std::thread::sleep(Duration::from_millis(2));
+        }
+    });
+
+    for i in 0..BURSTS * BURST {
+        assert_eq!(rx.pull_adaptive(), i);
+    }
+    p.join().unwrap();
+}
+
+#[test]
+fn blocking_recv_batch() {
+    const DELAY: Duration = Duration::from_millis(100);
+    const K: usize = 1000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_blocking();
+
+    // The clock starts before spawning, so that a late start of the thread
+    // doesn't shorten the measured wait
+    let start = Instant::now();
+    let c = std::thread::spawn(move || {
+        let mut out = vec![K];
+        let n = rx.recv_batch(&mut out, 3);
+        assert!((1..=3).contains(&n));
+        let elapsed = start.elapsed();
+        while out.len() <= K {
+            assert!(rx.pull_batch_blocking(&mut out, 8) > 0);
+        }
+        (out, elapsed, rx)
+    });
+
+    std::thread::sleep(DELAY);
+    for i in 0..K {
+        tx.send(i);
+    }
+    let (out, elapsed, mut rx) = c.join().unwrap();
+    assert!(elapsed >= DELAY);
+    assert_eq!(out[0], K);
+    assert!(out[1..].iter().copied().eq(0..K));
+
+    // A zero max moves nothing, once an element is available
+    tx.send(K);
+    let mut out = Vec::new();
+    assert_eq!(rx.pull_batch_blocking(&mut out, 0), 0);
+    assert!(out.is_empty());
+    assert_eq!(rx.recv(), K);
+}
@@ -0,0 +1,28 @@
+use ringbuffer_spsc::{BroadcastWriter, RingBuffer};
+
+#[test]
+fn broadcast() {
+    let (tx0, mut rx0) = RingBuffer::<String, 4>::init();
+    let (mut tx1, mut rx1) = RingBuffer::<String, 4>::init();
+    let (tx2, mut rx2) = RingBuffer::<String, 4>::init();
+    for i in 0..4 {
+        assert!(tx1.push(format!("old{i}")).is_none());
+    }
+
+    let mut tx = BroadcastWriter::new(vec![tx0, tx1, tx2]);
+    assert_eq!(tx.push("a".to_string()), Err(vec![1]));
+    assert_eq!(rx1.pull().as_deref(), Some("old0"));
+    assert_eq!(tx.push("b".to_string()), Ok(()));
+
+    // The element rejected by the full ring is not removed from the others
+    for rx in [&mut rx0, &mut rx2] {
+        assert_eq!(rx.pull().as_deref(), Some("a"));
+        assert_eq!(rx.pull().as_deref(), Some("b"));
+        assert!(rx.pull().is_none());
+    }
+    for i in 1..4 {
+        assert_eq!(rx1.pull(), Some(format!("old{i}")));
+    }
+    assert_eq!(rx1.pull().as_deref(), Some("b"));
+    assert_eq!(tx.into_inner().len(), 3);
+}
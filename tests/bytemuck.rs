@@ -0,0 +1,111 @@
+#![cfg(feature = "bytemuck")]
+use ringbuffer_spsc::{RingBuffer, RingBufferReader, RingBufferWriter};
+
+/// Moves the read and write indexes forward, one byte at a time, until the
+/// next byte to read is aligned for `u32`, and returns the index reached.
+fn align_head<const N: usize>(
+    tx: &mut RingBufferWriter<u8, N>,
+    rx: &mut RingBufferReader<u8, N>,
+) -> u8 {
+    let mut idx = 0;
+    loop {
+        assert!(tx.push(idx).is_none());
+        let (a, _) = rx.peek_n(1).unwrap();
+        if a.as_ptr().align_offset(4) == 0 {
+            return idx;
+        }
+        assert_eq!(rx.pull(), Some(idx));
+        idx += 1;
+    }
+}
+
+/// Returns the `u32` made of the bytes `i..i + 4`.
+fn word(i: u8) -> u32 {
+    u32::from_ne_bytes([i, i + 1, i + 2, i + 3])
+}
+
+#[test]
+fn as_slices_of() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 32>::init();
+    let head = align_head(&mut tx, &mut rx);
+    for i in head + 1..head + 16 {
+        assert!(tx.push(i).is_none());
+    }
+
+    let (a, b) = rx.as_slices_of::<u32>();
+    assert_eq!(
+        a,
+        [word(head), word(head + 4), word(head + 8), word(head + 12)]
+    );
+    assert!(b.is_empty());
+
+    // Reading is not consumed
+    assert_eq!(rx.as_slices_of::<u32>().0.len(), 4);
+    assert_eq!(rx.pull(), Some(head));
+}
+
+#[test]
+fn as_slices_of_misaligned() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 32>::init();
+    let head = align_head(&mut tx, &mut rx);
+    for i in head + 1..head + 16 {
+        assert!(tx.push(i).is_none());
+    }
+
+    // Nothing is returned until the next byte to read is aligned again
+    for i in head..head + 3 {
+        assert_eq!(rx.pull(), Some(i));
+        assert_eq!(rx.as_slices_of::<u32>(), (&[][..], &[][..]));
+    }
+    assert_eq!(rx.pull(), Some(head + 3));
+    let (a, b) = rx.as_slices_of::<u32>();
+    assert_eq!(a, [word(head + 4), word(head + 8), word(head + 12)]);
+    assert!(b.is_empty());
+}
+
+#[test]
+fn as_slices_of_trailing() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 32>::init();
+    let head = align_head(&mut tx, &mut rx);
+
+    // Fewer bytes than a whole u32
+    for i in head + 1..head + 3 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.as_slices_of::<u32>(), (&[][..], &[][..]));
+
+    // The trailing bytes are left out
+    for i in head + 3..head + 6 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.as_slices_of::<u32>(), (&[word(head)][..], &[][..]));
+}
+
+#[test]
+fn as_slices_of_wrap() {
+    // Elements of alignment 1, so that only the lengths decide what wraps
+    type U = [u8; 4];
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    for i in 0..10u8 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    for i in 10..22u8 {
+        assert!(tx.push(i).is_none());
+    }
+
+    // 6 bytes before the wrap: the element straddling it is left out, and so
+    // is the rest after the wrap
+    let (a, b) = rx.as_slices_of::<U>();
+    assert_eq!((a, b), (&[[10, 11, 12, 13]][..], &[][..]));
+
+    // 4 bytes before the wrap, continued after it
+    assert_eq!(rx.pull(), Some(10));
+    assert_eq!(rx.pull(), Some(11));
+    let (a, b) = rx.as_slices_of::<U>();
+    assert_eq!((a, b), (&[[12, 13, 14, 15]][..], &[[16, 17, 18, 19]][..]));
+
+    // 3 bytes before the wrap: the bytes after it are not returned instead
+    assert_eq!(rx.pull(), Some(12));
+    assert_eq!(rx.as_slices_of::<U>(), (&[][..], &[][..]));
+}
@@ -0,0 +1,172 @@
+use ringbuffer_spsc::RingBuffer;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hasher;
+
+#[test]
+fn pull_checksummed() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    // Move the indexes so that the data wraps around
+    for i in 0..10u8 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    let data: Vec<u8> = (100..112).collect();
+    for &b in data.iter() {
+        assert!(tx.push(b).is_none());
+    }
+
+    let mut dst = [0u8; 8];
+    let mut hasher = DefaultHasher::new();
+    assert_eq!(rx.pull_checksummed(&mut dst, &mut hasher), 8);
+    assert_eq!(dst, data[..8]);
+    let mut dst = [0u8; 8];
+    assert_eq!(rx.pull_checksummed(&mut dst, &mut hasher), 4);
+    assert_eq!(dst[..4], data[8..]);
+    assert_eq!(rx.pull(), None);
+
+    let mut expected = DefaultHasher::new();
+    expected.write(&data);
+    assert_eq!(hasher.finish(), expected.finish());
+}
+
+#[test]
+fn peek_checksum() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    // Move the indexes so that the data wraps around
+    for i in 0..10u8 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    let data: Vec<u8> = (100..112).collect();
+    for &b in data.iter() {
+        assert!(tx.push(b).is_none());
+    }
+
+    let mut hasher = DefaultHasher::new();
+    rx.peek_checksum(&mut hasher);
+    let mut expected = DefaultHasher::new();
+    expected.write(&data);
+    assert_eq!(hasher.finish(), expected.finish());
+
+    // Nothing has been consumed
+    for &b in data.iter() {
+        assert_eq!(rx.pull(), Some(b));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn drain_to_writer() {
+    // Accepts at most 3 bytes per call
+    struct Short(Vec<u8>);
+    impl std::io::Write for Short {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(3);
+            self.0.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    // Move the indexes so that the data wraps around
+    for i in 0..10u8 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    let data: Vec<u8> = (100..112).collect();
+    for &b in data.iter() {
+        assert!(tx.push(b).is_none());
+    }
+    let mut sink = Vec::new();
+    assert_eq!(rx.drain_to_writer(&mut sink).unwrap(), 12);
+    assert_eq!(sink, data);
+    assert_eq!(rx.pull(), None);
+
+    for &b in data.iter() {
+        assert!(tx.push(b).is_none());
+    }
+    let mut sink = Short(Vec::new());
+    assert_eq!(rx.drain_to_writer(&mut sink).unwrap(), 12);
+    assert_eq!(sink.0, data);
+    assert_eq!(rx.drain_to_writer(&mut sink).unwrap(), 0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn drain_to_writer_over_reporting() {
+    // Claims to have written more bytes than it was given
+    struct Lying;
+    impl std::io::Write for Lying {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len() + 100)
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    assert_eq!(tx.push_slice(&[1, 2, 3]), 3);
+    assert_eq!(rx.drain_to_writer(&mut Lying).unwrap(), 3);
+    // The read index stops at the write index
+    assert_eq!(rx.pull(), None);
+    assert_eq!(tx.push_slice(&[0; 20]), 16);
+    assert_eq!(rx.drain().count(), 16);
+    assert_eq!(rx.pull(), None);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fill_from_reader() {
+    // Returns at most 3 bytes per call
+    struct Short<'a>(&'a [u8]);
+    impl std::io::Read for Short<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.0.len()).min(3);
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    // Move the indexes away from the start of the buffer
+    for i in 0..10u8 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert!(tx.push(10).is_none());
+    let data: Vec<u8> = (100..140).collect();
+    let mut src = &data[..];
+    // The writer last saw the read index at 0, refreshing it only when the
+    // buffer seems full
+    assert_eq!(tx.fill_from_reader(&mut src).unwrap(), 5);
+    assert_eq!(tx.fill_from_reader(&mut src).unwrap(), 10);
+    assert_eq!(src, &data[15..]);
+    assert_eq!(tx.fill_from_reader(&mut src).unwrap(), 0);
+    assert_eq!(rx.pull(), Some(10));
+    for &b in data[..15].iter() {
+        assert_eq!(rx.pull(), Some(b));
+    }
+    assert_eq!(rx.pull(), None);
+
+    // A partial read only fills the first region
+    let mut src = Short(&data);
+    assert_eq!(tx.fill_from_reader(&mut src).unwrap(), 3);
+    for &b in data[..3].iter() {
+        assert_eq!(rx.pull(), Some(b));
+    }
+    assert_eq!(rx.pull(), None);
+
+    // The free space seen through the cached read index wraps around
+    let mut src = &data[..];
+    assert_eq!(tx.fill_from_reader(&mut src).unwrap(), 13);
+    for &b in data[..13].iter() {
+        assert_eq!(rx.pull(), Some(b));
+    }
+    assert_eq!(rx.pull(), None);
+}
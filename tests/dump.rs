@@ -0,0 +1,24 @@
+#![cfg(any(feature = "test-util", feature = "debug-checks"))]
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn dump_slots() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert!(rx.dump_slots().all(|(_, t)| t.is_none()));
+
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+        if i < 3 {
+            assert_eq!(rx.pull(), Some(i));
+        }
+    }
+    let dump: Vec<_> = rx.dump_slots().collect();
+    assert_eq!(
+        dump,
+        [(0, Some(&4)), (1, Some(&5)), (2, None), (3, Some(&3))]
+    );
+
+    assert!(tx.push(6).is_none());
+    let dump: Vec<_> = rx.dump_slots().map(|(_, t)| t.copied()).collect();
+    assert_eq!(dump, [Some(4), Some(5), Some(6), Some(3)]);
+}
@@ -0,0 +1,32 @@
+#![cfg(all(feature = "eventfd", target_os = "linux"))]
+use ringbuffer_spsc::RingBuffer;
+use std::os::fd::AsRawFd;
+
+fn is_readable(fd: &impl AsRawFd) -> bool {
+    let mut pfd = libc::pollfd {
+        fd: fd.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    assert!(unsafe { libc::poll(&mut pfd, 1, 0) } >= 0);
+    pfd.revents & libc::POLLIN != 0
+}
+
+#[test]
+fn eventfd() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init_eventfd().unwrap();
+    assert!(!is_readable(&rx));
+
+    assert!(tx.push(0).is_none());
+    assert!(is_readable(&rx));
+    assert!(tx.push(1).is_none());
+
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.pull(), Some(1));
+    assert_eq!(rx.pull(), None);
+    assert!(!is_readable(&rx));
+
+    assert!(tx.push(2).is_none());
+    assert!(is_readable(&rx));
+    assert_eq!(rx.pull(), Some(2));
+}
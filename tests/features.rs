@@ -0,0 +1,241 @@
+//! Exercises the public API enabled by each feature, with the same
+//! correctness assertions, so that the features keep working together
+//! whatever the combination they are enabled in.
+//!
+//! The combinations checked are:
+//!
+//! ```text
+//! cargo test --test features
+//! cargo test --test features --features std
+//! cargo test --test features --features async,metrics
+//! cargo test --test features --features timestamps,replay-log
+//! cargo test --test features --features debug-checks,std,test-util
+//! cargo test --test features --features u32-indices,bytemuck,arrayvec
+//! cargo test --test features --features eventfd,numa,metrics,u32-indices
+//! cargo test --test features --all-features
+//! ```
+use ringbuffer_spsc::{RingBuffer, RingBufferReader, RingBufferWriter};
+
+/// Fills and drains the ring buffer over several laps, checking that every
+/// element comes out once and in order, and that a full buffer rejects pushes.
+fn round_trip<const N: usize>(
+    tx: &mut RingBufferWriter<usize, N>,
+    rx: &mut RingBufferReader<usize, N>,
+) {
+    for lap in 0..3 {
+        for i in 0..N {
+            assert!(tx.push(lap * N + i).is_none());
+        }
+        assert_eq!(tx.push(usize::MAX), Some(usize::MAX));
+        for i in 0..N {
+            assert_eq!(rx.pull(), Some(lap * N + i));
+        }
+        assert_eq!(rx.pull(), None);
+    }
+}
+
+/// Transfers `k` elements from a producer thread, checking that every element
+/// comes out once and in order.
+fn threaded(
+    mut push: impl FnMut(usize) -> bool + Send + 'static,
+    mut pull: impl FnMut() -> Option<usize>,
+    k: usize,
+) {
+    let p = std::thread::spawn(move || {
+        let mut i = 0;
+        while i < k {
+            if push(i) {
+                i += 1;
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+    let mut i = 0;
+    while i < k {
+        if let Some(t) = pull() {
+            assert_eq!(t, i);
+            i += 1;
+        } else {
+            std::thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+    assert_eq!(pull(), None);
+}
+
+#[test]
+fn core() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    threaded(move |i| tx.push(i).is_none(), move || rx.pull(), 10_000);
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init_filled(0..8);
+    assert_eq!(rx.drain().collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+    round_trip(&mut tx, &mut rx);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init_blocking();
+    for i in 0..8 {
+        tx.send(i);
+    }
+    for i in 0..8 {
+        assert_eq!(rx.recv(), i);
+    }
+    threaded(
+        move |i| {
+            tx.send(i);
+            true
+        },
+        move || rx.pull_timeout(std::time::Duration::from_millis(100)),
+        10_000,
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_() {
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    let mut cx = Context::from_waker(Waker::noop());
+    for i in 0..8 {
+        let mut f = std::pin::pin!(tx.push_async(i));
+        assert_eq!(f.as_mut().poll(&mut cx), Poll::Ready(Ok(())));
+    }
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Ready(()));
+    for i in 1..8 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    round_trip(&mut tx, &mut rx);
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn metrics() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    // Every full buffer and every drained buffer reloads an index
+    assert!(tx.cache_miss_count() >= 3);
+    assert!(rx.cache_miss_count() >= 3);
+}
+
+#[cfg(feature = "timestamps")]
+#[test]
+fn timestamps() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    assert!(tx.push(0).is_none());
+    let (t, elapsed) = rx.pull_timed().unwrap();
+    assert_eq!(t, 0);
+    assert!(elapsed > std::time::Duration::ZERO);
+}
+
+#[cfg(feature = "replay-log")]
+#[test]
+fn replay_log() {
+    use ringbuffer_spsc::ReplayOp;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    assert_eq!(tx.replay_log().len(), 24);
+    assert!(tx.replay_log().iter().all(|e| e.op == ReplayOp::Push));
+    assert_eq!(rx.replay_log().len(), 24);
+    assert!(rx.replay_log().iter().all(|e| e.op == ReplayOp::Pull));
+}
+
+#[cfg(all(feature = "debug-checks", feature = "std"))]
+#[test]
+fn debug_checks() {
+    // Handles moved across threads, but never used from two at once
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    let (mut tx, mut rx) = std::thread::spawn(move || {
+        round_trip(&mut tx, &mut rx);
+        (tx, rx)
+    })
+    .join()
+    .unwrap();
+    round_trip(&mut tx, &mut rx);
+    threaded(move |i| tx.push(i).is_none(), move || rx.pull(), 10_000);
+}
+
+#[cfg(feature = "test-util")]
+#[test]
+fn test_util() {
+    use ringbuffer_spsc::SyncPoint;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let stores = Arc::new(AtomicUsize::new(0));
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    let c_stores = stores.clone();
+    tx.set_sync_hook(move |p| {
+        if p == SyncPoint::WriterStore {
+            c_stores.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    round_trip(&mut tx, &mut rx);
+    assert_eq!(stores.load(Ordering::Relaxed), 24);
+}
+
+#[cfg(feature = "u32-indices")]
+#[test]
+fn u32_indices() {
+    use ringbuffer_spsc::CapacityError;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(
+        RingBuffer::<u8, { 1 << 32 }>::try_init().err(),
+        Some(CapacityError::TooLarge)
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    assert_eq!(tx.push_slice(&[1, 0, 0, 0, 2, 0, 0, 0]), 8);
+    let (a, b) = rx.as_slices_of::<[u8; 4]>();
+    assert_eq!(a.len() + b.len(), 2);
+    assert_eq!(a[0], [1, 0, 0, 0]);
+    assert_eq!(rx.drain().count(), 8);
+}
+
+#[cfg(feature = "arrayvec")]
+#[test]
+fn arrayvec() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    round_trip(&mut tx, &mut rx);
+    for i in 0..8 {
+        assert!(tx.push(i).is_none());
+    }
+    let mut out = arrayvec::ArrayVec::<usize, 8>::new();
+    assert_eq!(rx.pull_into_arrayvec(&mut out), 8);
+    assert!(out.iter().copied().eq(0..8));
+}
+
+#[cfg(all(feature = "eventfd", target_os = "linux"))]
+#[test]
+fn eventfd() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init_eventfd().unwrap();
+    threaded(move |i| tx.push(i).is_none(), move || rx.pull(), 10_000);
+}
+
+#[cfg(all(feature = "numa", target_os = "linux"))]
+#[test]
+fn numa() {
+    // NUMA may not be available, e.g. in a container denying mbind(2)
+    match RingBuffer::<usize, 8>::init_on_node(0) {
+        Ok((mut tx, mut rx)) => round_trip(&mut tx, &mut rx),
+        Err(e) => eprintln!("NUMA not available, skipping: {e}"),
+    }
+}
@@ -0,0 +1,95 @@
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn growing() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init_growing();
+    assert!(rx.pull().is_none());
+
+    // The buffer grows instead of rejecting elements
+    for i in 0..100 {
+        tx.push(i.to_string());
+    }
+    for i in 0..100 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert!(rx.pull().is_none());
+
+    // Elements left in the buffer are dropped along with it
+    for i in 0..10 {
+        tx.push(i.to_string());
+    }
+}
+
+#[test]
+fn growing_doubles_capacity() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init_growing();
+    assert_eq!(tx.capacity(), 4);
+    for i in 0..4 {
+        tx.push(i);
+    }
+    assert_eq!(tx.capacity(), 4);
+    tx.push(4);
+    assert_eq!(tx.capacity(), 8);
+    for i in 5..100 {
+        tx.push(i);
+    }
+    // 4 + 8 + 16 + 32 < 100 <= 4 + 8 + 16 + 32 + 64
+    assert_eq!(tx.capacity(), 64);
+
+    // The reader switches to the larger buffers once it drains the smaller ones
+    assert_eq!(rx.capacity(), 4);
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), Some(4));
+    assert_eq!(rx.capacity(), 8);
+    for i in 5..100 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.capacity(), 64);
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn growing_drop_chain() {
+    // A chain of ring buffers never drained by the reader is freed along
+    // with it, whichever handle is dropped first
+    let (mut tx, rx) = RingBuffer::<String, 1>::init_growing();
+    for i in 0..1000 {
+        tx.push(i.to_string());
+    }
+    drop(rx);
+    drop(tx);
+
+    let (mut tx, rx) = RingBuffer::<String, 1>::init_growing();
+    for i in 0..1000 {
+        tx.push(i.to_string());
+    }
+    drop(tx);
+    drop(rx);
+}
+
+#[test]
+fn growing_slow_reader() {
+    const N: usize = 100_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init_growing();
+
+    let p = std::thread::spawn(move || {
+        for i in 0..N {
+            tx.push(i);
+        }
+    });
+
+    let mut i = 0;
+    while i < N {
+        if let Some(t) = rx.pull() {
+            assert_eq!(t, i);
+            i += 1;
+            if i % 1000 == 0 {
+                std::thread::sleep(std::time::Duration::from_micros(100));
+            }
+        }
+    }
+    p.join().unwrap();
+    assert!(rx.pull().is_none());
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "test-util")]
+use ringbuffer_spsc::{RingBuffer, SyncPoint};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn sync_hook_interleaving() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    let (mut hook_tx, hook_rx) = RingBuffer::<usize, 4>::init();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    // Every time the writer publishes, the reader pulls right away
+    let c_log = log.clone();
+    rx.set_sync_hook(move |p| c_log.lock().unwrap().push(p));
+    let c_log = log.clone();
+    let mut rx = Some(rx);
+    tx.set_sync_hook(move |p| {
+        c_log.lock().unwrap().push(p);
+        if p == SyncPoint::WriterStore {
+            if let Some(t) = rx.as_mut().unwrap().pull() {
+                assert!(hook_tx.push(t).is_none());
+            }
+        }
+    });
+
+    assert_eq!(tx.push_slice_until(&[0, 1, 2, 3], 2), 3);
+    assert!(tx.push(3).is_none());
+    drop(tx);
+
+    let mut hook_rx = hook_rx;
+    assert_eq!(hook_rx.pull(), Some(0));
+    assert_eq!(hook_rx.pull(), Some(1));
+    assert_eq!(hook_rx.pull(), None);
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            // Bulk push of 3 elements: the reader loads the write index
+            SyncPoint::WriterStore,
+            SyncPoint::ReaderLoad,
+            SyncPoint::ReaderStore,
+            // Single push: the reader still has elements in its cached range
+            SyncPoint::WriterStore,
+            SyncPoint::ReaderStore,
+        ]
+    );
+}
+
+#[test]
+fn pull_bounded_zero_lag() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert!(tx.push(0).is_none());
+
+    // The writer pushes an element every time the reader loads the write index
+    let mut i = 1;
+    rx.set_sync_hook(move |p| {
+        if p == SyncPoint::ReaderLoad {
+            assert!(tx.push(i).is_none());
+            i += 1;
+        }
+    });
+    assert_eq!(rx.pull_bounded(0), None);
+    assert_eq!(rx.pull_bounded(0), None);
+}
+
+#[test]
+fn peek_cursor_consume_stores_once() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    for i in 0..3 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    let stores = Arc::new(Mutex::new(0));
+    let c_stores = stores.clone();
+    rx.set_sync_hook(move |p| {
+        if p == SyncPoint::ReaderStore {
+            *c_stores.lock().unwrap() += 1;
+        }
+    });
+
+    let mut cursor = rx.peekable();
+    while cursor.peek_next().is_some() {}
+    assert_eq!(cursor.consume(), 3);
+    assert_eq!(*stores.lock().unwrap(), 1);
+    assert!(rx.pull().is_none());
+}
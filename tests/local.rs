@@ -0,0 +1,37 @@
+use ringbuffer_spsc::LocalRingBuffer;
+
+#[test]
+fn local() {
+    let mut rb = LocalRingBuffer::<String>::new(4);
+    assert_eq!(rb.capacity(), 4);
+    assert!(rb.is_empty());
+    assert_eq!(rb.peek(), None);
+    assert_eq!(rb.pull(), None);
+
+    // Wrap the indexes around several times
+    for lap in 0..5 {
+        for i in 0..4 {
+            assert!(rb.push(format!("{lap}-{i}")).is_none());
+        }
+        assert!(rb.is_full());
+        assert_eq!(rb.push(String::new()), Some(String::new()));
+        for i in 0..3 {
+            assert_eq!(rb.peek(), Some(&format!("{lap}-{i}")));
+            assert_eq!(rb.pull(), Some(format!("{lap}-{i}")));
+        }
+        assert_eq!(rb.len(), 1);
+        assert_eq!(rb.pull(), Some(format!("{lap}-3")));
+        assert!(rb.is_empty());
+    }
+
+    // Elements left in the buffer are dropped along with it
+    for i in 0..3 {
+        assert!(rb.push(i.to_string()).is_none());
+    }
+}
+
+#[test]
+#[should_panic(expected = "power of two")]
+fn local_invalid_capacity() {
+    let _ = LocalRingBuffer::<usize>::new(12);
+}
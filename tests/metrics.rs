@@ -0,0 +1,90 @@
+#![cfg(feature = "metrics")]
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn cache_miss_count() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(tx.cache_miss_count(), 0);
+    assert_eq!(rx.cache_miss_count(), 0);
+
+    // Filling the buffer doesn't need to load the read index
+    for i in 0..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(tx.cache_miss_count(), 0);
+
+    // A lagging consumer makes the producer reload the read index
+    assert!(tx.push(4).is_some());
+    assert_eq!(tx.cache_miss_count(), 1);
+    assert_eq!(rx.pull(), Some(0));
+    assert!(tx.push(4).is_none());
+    assert!(tx.push(5).is_some());
+    assert_eq!(tx.cache_miss_count(), 3);
+
+    // The consumer reloads the write index only once it caught up with it
+    assert_eq!(rx.cache_miss_count(), 1);
+    for i in 1..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.cache_miss_count(), 1);
+    assert_eq!(rx.pull(), Some(4));
+    assert_eq!(rx.cache_miss_count(), 2);
+    assert_eq!(rx.pull(), None);
+    assert_eq!(rx.cache_miss_count(), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn consumer_stall_duration() {
+    use std::time::Duration;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    // An empty buffer doesn't stall the reader
+    assert!(tx.consumer_stall_duration().is_none());
+    std::thread::sleep(Duration::from_millis(5));
+    assert!(tx.consumer_stall_duration().is_none());
+
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    let first = tx.consumer_stall_duration().unwrap();
+    std::thread::sleep(Duration::from_millis(10));
+    let second = tx.consumer_stall_duration().unwrap();
+    assert!(second >= first + Duration::from_millis(10));
+
+    // The reader made progress
+    assert_eq!(rx.pull(), Some(0));
+    assert!(tx.consumer_stall_duration().is_none());
+    assert!(tx.consumer_stall_duration().unwrap() < second);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn push_with_hint() {
+    use std::time::Duration;
+    const INTERVAL: Duration = Duration::from_millis(20);
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    // A steady slow consumer
+    let c = std::thread::spawn(move || {
+        for i in 0..20 {
+            std::thread::sleep(INTERVAL);
+            assert_eq!(rx.pull(), Some(i));
+        }
+    });
+
+    let mut hint = Duration::ZERO;
+    let mut i = 0;
+    while i < 20 {
+        match tx.push_with_hint(i) {
+            Ok(()) => i += 1,
+            Err((t, h)) => {
+                assert_eq!(t, i);
+                hint = h;
+                std::thread::sleep(h.max(Duration::from_millis(1)) / 4);
+            }
+        }
+    }
+    c.join().unwrap();
+    assert!(hint >= INTERVAL / 2 && hint <= INTERVAL * 3, "{hint:?}");
+}
@@ -0,0 +1,99 @@
+use ringbuffer_spsc::{CapacityError, RingBuffer};
+
+#[test]
+fn migrating() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init_migrating();
+    assert!(rx.pull().is_none());
+    for i in 0..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.push(4.to_string()).is_some());
+
+    assert_eq!(tx.begin_migration(0), Err(CapacityError::Zero));
+    let migration = tx.begin_migration(16).unwrap();
+    assert_eq!(migration.position(), 4);
+    assert_eq!(tx.capacity(), 16);
+    for i in 4..20 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.push(20.to_string()).is_some());
+
+    // Migrations can be repeated before the reader catches up
+    let migration = tx.begin_migration(3).unwrap();
+    assert_eq!(migration.position(), 20);
+    for i in 20..23 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.push(23.to_string()).is_some());
+
+    // The previous ring buffers are drained first
+    assert_eq!(rx.capacity(), 4);
+    for i in 0..23 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+        let capacity = match i {
+            0..=3 => 4,
+            4..=19 => 16,
+            _ => 3,
+        };
+        assert_eq!(rx.capacity(), capacity);
+    }
+    assert!(rx.pull().is_none());
+
+    // Elements left in the buffers are dropped along with them
+    assert!(tx.push(23.to_string()).is_none());
+    assert!(tx.begin_migration(2).is_ok());
+    assert!(tx.push(24.to_string()).is_none());
+}
+
+#[test]
+fn migrating_unclaimed() {
+    // The handoffs are freed even if the reader never switches
+    let (mut tx, rx) = RingBuffer::<String, 4>::init_migrating();
+    assert!(tx.push(0.to_string()).is_none());
+    assert!(tx.begin_migration(16).is_ok());
+    assert!(tx.push(1.to_string()).is_none());
+    assert!(tx.begin_migration(2).is_ok());
+    assert!(tx.push(2.to_string()).is_none());
+    drop(rx);
+}
+
+#[test]
+fn migrating_concurrent() {
+    const K: usize = 200_000;
+    const CAPACITIES: [usize; 4] = [64, 8, 1000, 3];
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_migrating();
+
+    let p = std::thread::spawn(move || {
+        let mut i = 0;
+        let mut migrations = 0;
+        while i < K {
+            if migrations < CAPACITIES.len() && i == (migrations + 1) * (K / 5) {
+                let migration = tx.begin_migration(CAPACITIES[migrations]).unwrap();
+                assert_eq!(migration.position(), i);
+                migrations += 1;
+            }
+            if tx.push(i).is_none() {
+                i += 1;
+            } else {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    let mut i = 0;
+    while i < K {
+        if let Some(t) = rx.pull() {
+            assert_eq!(t, i);
+            let capacity = match i / (K / 5) {
+                0 => 16,
+                n => CAPACITIES[n - 1],
+            };
+            assert_eq!(rx.capacity(), capacity);
+            i += 1;
+        } else {
+            std::thread::yield_now();
+        }
+    }
+    p.join().unwrap();
+    assert!(rx.pull().is_none());
+}
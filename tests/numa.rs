@@ -0,0 +1,88 @@
+#![cfg(all(feature = "numa", target_os = "linux"))]
+use ringbuffer_spsc::{RingBuffer, RingBufferReader, RingBufferWriter};
+use std::io;
+
+/// Creates a ring buffer bound to node 0, or returns `None` if NUMA is not
+/// available, e.g. in a container denying `mbind(2)`.
+fn on_node_0<T, const N: usize>() -> Option<(RingBufferWriter<T, N>, RingBufferReader<T, N>)> {
+    match RingBuffer::<T, N>::init_on_node(0) {
+        Ok(handles) => Some(handles),
+        Err(e) => {
+            let errno = e.raw_os_error();
+            assert!(
+                [libc::ENOSYS, libc::EPERM, libc::EINVAL].contains(&errno.unwrap_or(0)),
+                "Unexpected error: {e}"
+            );
+            eprintln!("NUMA not available, skipping: {e}");
+            None
+        }
+    }
+}
+
+#[test]
+fn init_on_node() {
+    let Some((mut tx, mut rx)) = on_node_0::<usize, 4096>() else {
+        return;
+    };
+    for i in 0..10_000 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+}
+
+#[test]
+fn init_on_node_small() {
+    // Less than a page of elements, each with a destructor
+    let Some((mut tx, mut rx)) = on_node_0::<String, 4>() else {
+        return;
+    };
+    for i in 0..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.push("4".to_string()).is_some());
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+    // The elements left are dropped with the ring buffer
+    assert!(tx.push("4".to_string()).is_none());
+}
+
+#[test]
+fn init_on_node_zst() {
+    // Nothing to bind, so it succeeds even where NUMA is not available
+    let (mut tx, mut rx) = RingBuffer::<(), 8>::init_on_node(0).unwrap();
+    for _ in 0..20 {
+        assert!(tx.push(()).is_none());
+        assert_eq!(rx.pull(), Some(()));
+    }
+}
+
+#[test]
+fn init_on_node_swap_storage() {
+    let Some((mut tx, mut rx)) = on_node_0::<String, 4>() else {
+        return;
+    };
+    assert!(tx.push("a".to_string()).is_none());
+    assert!(tx.push("b".to_string()).is_none());
+    let storage = (0..4).map(|_| std::mem::MaybeUninit::uninit()).collect();
+    let (mut old, len) = RingBuffer::swap_storage(&mut tx, &mut rx, storage);
+    assert_eq!(len, 2);
+    for (slot, expected) in old[..len].iter_mut().zip(["a", "b"]) {
+        assert_eq!(unsafe { slot.assume_init_read() }, expected);
+    }
+}
+
+#[test]
+fn init_on_invalid_node() {
+    let e = RingBuffer::<usize, 4096>::init_on_node(1 << 16)
+        .err()
+        .unwrap();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn init_on_node_out_of_range() {
+    // Rejected before sizing the node mask, whether NUMA is available or not
+    for node in [1 << 10, u32::MAX] {
+        let err = RingBuffer::<usize, 4>::init_on_node(node).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,60 @@
+#![cfg(all(
+    feature = "debug-checks",
+    feature = "std",
+    feature = "test-util",
+    debug_assertions
+))]
+use ringbuffer_spsc::{RingBuffer, SyncPoint};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[test]
+fn concurrent_reader_panics() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    let panicked = Arc::new(AtomicBool::new(false));
+
+    // While the reader is in the middle of pulling, another thread tries to
+    // pull from it too, which is only possible by going around the borrow
+    // checker.
+    let ptr = &mut rx as *mut _ as usize;
+    let p = panicked.clone();
+    let mut done = false;
+    rx.set_sync_hook(move |point| {
+        if point == SyncPoint::ReaderLoad && !done {
+            done = true;
+            let res = std::thread::spawn(move || {
+                let rx = unsafe { &mut *(ptr as *mut ringbuffer_spsc::RingBufferReader<usize, 8>) };
+                rx.pull()
+            })
+            .join();
+            p.store(res.is_err(), Ordering::Relaxed);
+        }
+    });
+    assert_eq!(rx.pull(), None);
+    assert!(panicked.load(Ordering::Relaxed));
+
+    // The handle is usable again from the original thread
+    assert!(tx.push(0).is_none());
+    assert_eq!(rx.pull(), Some(0));
+}
+
+#[test]
+fn handoff() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    assert!(tx.push(0).is_none());
+    assert_eq!(rx.pull(), Some(0));
+
+    // Moving the handles to other threads is fine
+    let p = std::thread::spawn(move || {
+        assert!(tx.push(1).is_none());
+        tx
+    });
+    let mut tx = p.join().unwrap();
+    let c = std::thread::spawn(move || {
+        assert_eq!(rx.pull(), Some(1));
+        rx
+    });
+    let mut rx = c.join().unwrap();
+    assert!(tx.push(2).is_none());
+    assert_eq!(rx.pull(), Some(2));
+}
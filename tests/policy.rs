@@ -0,0 +1,121 @@
+use ringbuffer_spsc::{FullPolicy, Overwrite, Reject, RingBuffer, RingBufferWriter};
+
+#[test]
+fn full_policy_reject() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(tx.full_policy(), FullPolicy::Reject);
+    for i in 0..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(tx.push(4), Some(4));
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn full_policy_drop_newest() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    tx.set_full_policy(FullPolicy::DropNewest);
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+    }
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn full_policy_drop_oldest() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init_lossy();
+    tx.set_full_policy(FullPolicy::DropOldest);
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+    }
+    for i in 2..6 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+#[should_panic]
+fn full_policy_drop_oldest_requires_lossy() {
+    let (mut tx, _rx) = RingBuffer::<usize, 4>::init();
+    tx.set_full_policy(FullPolicy::DropOldest);
+}
+
+#[test]
+fn full_policy_drop_oldest_concurrent() {
+    const N: usize = 1_000_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_lossy();
+    tx.set_full_policy(FullPolicy::DropOldest);
+
+    let p = std::thread::spawn(move || {
+        for i in 0..N {
+            assert!(tx.push(i).is_none());
+        }
+    });
+
+    let mut last = None;
+    while !p.is_finished() || last != Some(N - 1) {
+        if let Some(c) = rx.pull() {
+            assert!(last.is_none_or(|l| c > l));
+            last = Some(c);
+        }
+    }
+    p.join().unwrap();
+}
+
+#[test]
+fn overflow_policy_reject() {
+    let (mut tx, mut rx): (RingBufferWriter<usize, 4, Reject>, _) = RingBuffer::init();
+    for i in 0..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(tx.push(4), Some(4));
+    assert_eq!(tx.push_with(|| 4).map(|f| f()), Some(4));
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn overflow_policy_overwrite() {
+    let (mut tx, mut rx): (RingBufferWriter<usize, 4, Overwrite>, _) = RingBuffer::init_overwrite();
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+    }
+    assert!(tx.push_with(|| 6).is_none());
+    for i in 3..7 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn push_overwrite() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init_lossy();
+    for i in 0..4 {
+        assert!(tx.push_overwrite(i.to_string()).is_none());
+    }
+    // The oldest element is evicted and handed back
+    for i in 4..10 {
+        assert_eq!(tx.push_overwrite(i.to_string()), Some((i - 4).to_string()));
+        assert_eq!(tx.descriptor().len(), 4);
+    }
+    for i in 6..10 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+#[should_panic]
+fn push_overwrite_not_lossy() {
+    let (mut tx, _rx) = RingBuffer::<usize, 4>::init();
+    tx.push_overwrite(0);
+}
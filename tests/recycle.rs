@@ -0,0 +1,35 @@
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn recycling() {
+    let pool: Vec<Vec<u8>> = (0..4).map(|_| Vec::with_capacity(64)).collect();
+    let ptrs: Vec<*const u8> = pool.iter().map(|b| b.as_ptr()).collect();
+    let (mut producer, mut consumer) = RingBuffer::<Vec<u8>, 4>::init_recycling(pool);
+    assert!(consumer.get_full().is_none());
+
+    for round in 0..10u8 {
+        // Fill all the buffers
+        for i in 0..4 {
+            let mut buf = producer.get_empty().unwrap();
+            buf.clear();
+            buf.extend_from_slice(&[round, i]);
+            assert!(producer.submit_full(buf).is_none());
+        }
+        assert!(producer.get_empty().is_none());
+
+        for i in 0..4 {
+            let buf = consumer.get_full().unwrap();
+            assert_eq!(buf, [round, i]);
+            // The buffers are recycled rather than reallocated
+            assert!(ptrs.contains(&buf.as_ptr()));
+            assert!(consumer.return_empty(buf).is_none());
+        }
+        assert!(consumer.get_full().is_none());
+    }
+}
+
+#[test]
+#[should_panic]
+fn recycling_pool_too_large() {
+    let _ = RingBuffer::<usize, 4>::init_recycling(0..5);
+}
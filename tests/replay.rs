@@ -0,0 +1,52 @@
+#![cfg(feature = "replay-log")]
+use ringbuffer_spsc::{ReplayEvent, ReplayOp, RingBuffer};
+
+fn event(op: ReplayOp, idx: usize, len: usize) -> ReplayEvent {
+    ReplayEvent { op, idx, len }
+}
+
+#[test]
+fn replay_log() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    assert!(tx.push(0).is_none());
+    assert_eq!(tx.push_slice(&[1, 2, 3, 4]), 4);
+    assert_eq!(rx.pull(), Some(0));
+    let mut dst = [0; 2];
+    assert_eq!(rx.pull_slice(&mut dst), 2);
+    rx.consume(1);
+    assert_eq!(rx.pull(), Some(4));
+    assert_eq!(tx.push_slice(&[5, 6]), 2);
+    rx.clear();
+
+    assert_eq!(
+        tx.replay_log(),
+        [
+            event(ReplayOp::Push, 0, 1),
+            event(ReplayOp::Push, 1, 4),
+            event(ReplayOp::Push, 5, 2),
+        ]
+    );
+    assert_eq!(
+        rx.replay_log(),
+        [
+            event(ReplayOp::Pull, 0, 1),
+            event(ReplayOp::Pull, 1, 2),
+            event(ReplayOp::Skip, 3, 1),
+            event(ReplayOp::Pull, 4, 1),
+            event(ReplayOp::Clear, 5, 2),
+        ]
+    );
+}
+
+#[test]
+fn replay_log_bounded() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    for i in 0..10_000 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    let log = rx.replay_log();
+    assert!(log.len() >= 512 && log.len() <= 1024);
+    assert_eq!(log.last(), Some(&event(ReplayOp::Pull, 9_999, 1)));
+    assert!(log.windows(2).all(|w| w[0].idx + 1 == w[1].idx));
+}
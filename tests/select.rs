@@ -0,0 +1,38 @@
+use ringbuffer_spsc::{RingBuffer, Selector};
+
+#[test]
+fn select_pull() {
+    let (mut tx0, mut rx0) = RingBuffer::<usize, 8>::init();
+    let (mut tx1, mut rx1) = RingBuffer::<usize, 8>::init();
+    let mut selector = Selector::new();
+    assert!(selector.select_pull(&mut [&mut rx0, &mut rx1]).is_none());
+
+    for i in 0..4 {
+        assert!(tx0.push(i).is_none());
+        assert!(tx1.push(10 + i).is_none());
+    }
+    // Both readers are served in turn
+    let pulled: Vec<_> =
+        std::iter::from_fn(|| selector.select_pull(&mut [&mut rx0, &mut rx1])).collect();
+    assert_eq!(
+        pulled,
+        [
+            (0, 0),
+            (1, 10),
+            (0, 1),
+            (1, 11),
+            (0, 2),
+            (1, 12),
+            (0, 3),
+            (1, 13)
+        ]
+    );
+
+    // An empty reader is skipped
+    assert!(tx1.push(14).is_none());
+    assert_eq!(
+        selector.select_pull(&mut [&mut rx0, &mut rx1]),
+        Some((1, 14))
+    );
+    assert!(selector.select_pull(&mut [&mut rx0, &mut rx1]).is_none());
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "serde")]
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+fn snapshot_serialize() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 4>::init();
+    assert_eq!(serde_json::to_string(&rx.snapshot()).unwrap(), "[]");
+    // Move the indexes so that the data wraps around
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    for i in 1..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(serde_json::to_string(&rx.snapshot()).unwrap(), "[1,2,3]");
+    assert_eq!(rx.pull(), Some(1));
+}
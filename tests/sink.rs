@@ -0,0 +1,122 @@
+#![cfg(feature = "async")]
+use ringbuffer_spsc::RingBuffer;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(f: F) -> F::Output {
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    let mut f = pin!(f);
+    loop {
+        match f.as_mut().poll(&mut cx) {
+            Poll::Ready(r) => return r,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn push_async() {
+    const N: usize = 100_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+
+    let c = thread::spawn(move || {
+        for i in 0..N {
+            loop {
+                if let Some(t) = rx.pull() {
+                    assert_eq!(t, i);
+                    break;
+                }
+                thread::yield_now();
+            }
+        }
+    });
+    block_on(async {
+        for i in 0..N {
+            tx.push_async(i).await.unwrap();
+        }
+    });
+    c.join().unwrap();
+}
+
+#[test]
+fn poll_ready() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Ready(()));
+    assert_eq!(tx.start_send(0), Ok(()));
+    assert_eq!(tx.start_send(1), Ok(()));
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Pending);
+    assert_eq!(tx.start_send(2), Err(2));
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(tx.poll_ready(&mut cx), Poll::Ready(()));
+    assert_eq!(tx.start_send(2), Ok(()));
+
+    // Doesn't wait forever once the reader is gone
+    drop(rx);
+    assert_eq!(block_on(tx.push_async(3)), Err(3));
+}
+
+#[test]
+fn forward() {
+    use futures::StreamExt;
+
+    const N: usize = 100_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+
+    let c = thread::spawn(move || {
+        for i in 0..N {
+            loop {
+                if let Some(t) = rx.pull() {
+                    assert_eq!(t, i);
+                    break;
+                }
+                thread::yield_now();
+            }
+        }
+    });
+    futures::executor::block_on(async {
+        futures::stream::iter(0..N)
+            .map(Ok)
+            .forward(&mut tx)
+            .await
+            .unwrap();
+    });
+    c.join().unwrap();
+}
+
+#[test]
+fn lossy_reader_wakes_writer() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init_lossy();
+
+    assert!(tx.push(0).is_none());
+    assert!(tx.push(1).is_none());
+    let c = thread::spawn(move || {
+        thread::sleep(std::time::Duration::from_millis(10));
+        assert_eq!(rx.pull(), Some(0));
+        rx
+    });
+    assert_eq!(block_on(tx.push_async(2)), Ok(()));
+    let rx = c.join().unwrap();
+
+    // Doesn't wait forever once the lossy reader is gone
+    let c = thread::spawn(move || {
+        thread::sleep(std::time::Duration::from_millis(10));
+        drop(rx);
+    });
+    assert_eq!(block_on(tx.push_async(3)), Err(3));
+    c.join().unwrap();
+}
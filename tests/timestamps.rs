@@ -0,0 +1,22 @@
+#![cfg(feature = "timestamps")]
+use ringbuffer_spsc::RingBuffer;
+use std::time::Duration;
+
+#[test]
+fn pull_timed() {
+    const DELAY: Duration = Duration::from_millis(20);
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert!(rx.pull_timed().is_none());
+
+    assert!(tx.push(0).is_none());
+    let (t, fast) = rx.pull_timed().unwrap();
+    assert_eq!(t, 0);
+    assert!(fast > Duration::ZERO);
+
+    assert!(tx.push_with_space(1).is_ok());
+    std::thread::sleep(DELAY);
+    let (t, slow) = rx.pull_timed().unwrap();
+    assert_eq!(t, 1);
+    assert!(slow >= DELAY);
+    assert!(slow > fast);
+}
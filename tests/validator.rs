@@ -0,0 +1,24 @@
+use ringbuffer_spsc::RingBuffer;
+
+#[test]
+#[cfg_attr(feature = "debug-checks", should_panic(expected = "failed validation"))]
+fn validator() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    tx.set_validator(|t| *t < 10);
+
+    assert!(tx.push(1).is_none());
+    assert!(tx.push(10).is_none());
+    assert_eq!(rx.pull(), Some(1));
+    assert_eq!(rx.pull(), Some(10));
+}
+
+#[test]
+#[cfg_attr(feature = "debug-checks", should_panic(expected = "failed validation"))]
+fn validator_batch() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    tx.set_validator(|t| *t < 10);
+
+    assert_eq!(tx.push_batch_report(vec![1, 10, 2, 3, 4]), [4]);
+    assert_eq!(rx.pull(), Some(1));
+    assert_eq!(rx.pull(), Some(10));
+}
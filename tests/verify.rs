@@ -1,4 +1,4 @@
-use ringbuffer_spsc::RingBuffer;
+use ringbuffer_spsc::{CapacityError, RingBuffer, RingBufferReader};
 
 #[test]
 fn it_works() {
@@ -31,3 +31,1291 @@ fn it_works() {
     p.join().unwrap();
     c.join().unwrap();
 }
+
+#[test]
+fn pull_boxed() {
+    #[derive(Debug, PartialEq)]
+    struct Large([u64; 512]);
+
+    let (mut tx, mut rx) = RingBuffer::<Large, 2>::init();
+    assert!(tx.push(Large([1; 512])).is_none());
+    assert!(tx.push(Large([2; 512])).is_none());
+    assert!(tx.push(Large([3; 512])).is_some());
+
+    let b = rx.pull_boxed().unwrap();
+    assert_eq!(*b, Large([1; 512]));
+    // The slot has been freed
+    assert!(tx.push(Large([3; 512])).is_none());
+    assert_eq!(*rx.pull_boxed().unwrap(), Large([2; 512]));
+    assert_eq!(rx.pull(), Some(Large([3; 512])));
+    assert!(rx.pull_boxed().is_none());
+}
+
+#[test]
+fn init_from_box() {
+    use std::mem::MaybeUninit;
+
+    let storage: Box<[MaybeUninit<String>]> = (0..8).map(|_| MaybeUninit::uninit()).collect();
+    let ptr = storage.as_ptr();
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init_from_box(storage);
+
+    for i in 0..20 {
+        assert!(tx.push(i.to_string()).is_none());
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    // The provided storage is used as backing store
+    assert!(tx.push("last".to_string()).is_none());
+    assert_eq!(unsafe { (*ptr.add(20 % 8)).assume_init_ref() }, "last");
+    assert_eq!(rx.pull().as_deref(), Some("last"));
+}
+
+#[test]
+#[should_panic]
+fn init_from_box_wrong_length() {
+    use std::mem::MaybeUninit;
+
+    let storage: Box<[MaybeUninit<u8>]> = Box::new([MaybeUninit::uninit(); 4]);
+    let _ = RingBuffer::<u8, 8>::init_from_box(storage);
+}
+
+#[test]
+fn pull_aligned() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 16>::init();
+    let mut dst = [0u32; 16];
+
+    // Fewer than a full vector
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull_aligned::<4>(&mut dst), 0);
+
+    // Rounded down to a multiple of the vector width
+    for i in 3..10 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull_aligned::<4>(&mut dst), 8);
+    assert_eq!(dst[..8], [0, 1, 2, 3, 4, 5, 6, 7]);
+
+    // Across the wrap and limited by the destination length
+    for i in 10..24 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull_aligned::<4>(&mut dst[..10]), 8);
+    assert_eq!(dst[..8], [8, 9, 10, 11, 12, 13, 14, 15]);
+    assert_eq!(rx.pull_aligned::<4>(&mut dst), 8);
+    assert_eq!(dst[..8], [16, 17, 18, 19, 20, 21, 22, 23]);
+    assert_eq!(rx.pull_aligned::<1>(&mut dst), 0);
+}
+
+#[test]
+fn descriptor() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    let d = tx.descriptor();
+    assert_eq!(d.capacity(), 4);
+    assert!(d.is_empty());
+
+    assert!(tx.push(0).is_none());
+    assert!(tx.push(1).is_none());
+    assert_eq!(d.len(), 2);
+    assert_eq!(rx.descriptor().len(), 2);
+
+    assert!(tx.push(2).is_none());
+    assert!(tx.push(3).is_none());
+    assert!(d.is_full());
+
+    assert_eq!(rx.pull(), Some(0));
+    let d = std::thread::spawn(move || d.len()).join().unwrap();
+    assert_eq!(d, 3);
+}
+
+#[test]
+fn peekable() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    for i in 0..5 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+
+    let mut cursor = rx.peekable();
+    assert_eq!(cursor.peek_next().map(String::as_str), Some("0"));
+    assert_eq!(cursor.peek_next().map(String::as_str), Some("1"));
+    assert_eq!(cursor.peek_next().map(String::as_str), Some("2"));
+    assert_eq!(cursor.consume(), 3);
+
+    assert_eq!(rx.pull().as_deref(), Some("3"));
+    assert_eq!(rx.pull().as_deref(), Some("4"));
+    assert_eq!(rx.pull(), None);
+
+    // The cursor is bounded by the available elements
+    assert!(tx.push("5".to_string()).is_none());
+    let mut cursor = rx.peekable();
+    assert!(cursor.peek_next().is_some());
+    assert!(cursor.peek_next().is_none());
+}
+
+#[test]
+fn push_slice_until() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 8>::init();
+    let drain =
+        |rx: &mut RingBufferReader<u8, 8>| std::iter::from_fn(|| rx.pull()).collect::<Vec<_>>();
+
+    // Sentinel in the middle
+    assert_eq!(tx.push_slice_until(&[1, 2, 0, 3, 4], 0), 3);
+    assert_eq!(drain(&mut rx), [1, 2, 0]);
+
+    // Sentinel as first element
+    assert_eq!(tx.push_slice_until(&[0, 1, 2], 0), 1);
+    assert_eq!(drain(&mut rx), [0]);
+
+    // No sentinel: push as much as fits, across the wrap
+    assert_eq!(tx.push_slice_until(&[1, 2, 3, 4, 5, 6, 7, 8, 9], 0), 8);
+    assert_eq!(tx.push_slice_until(&[1, 0], 0), 0);
+    assert_eq!(drain(&mut rx), [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn pull_slice_until() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 8>::init();
+    let mut dst = [0u8; 8];
+
+    assert_eq!(tx.push_slice_until(b"ab\nc", b'\n'), 3);
+    assert_eq!(tx.push_slice_until(b"c", b'\n'), 1);
+    assert_eq!(rx.pull_slice_until(&mut dst, b'\n'), (3, true));
+    assert_eq!(&dst[..3], b"ab\n");
+
+    // No sentinel available yet
+    assert_eq!(rx.pull_slice_until(&mut dst, b'\n'), (1, false));
+    assert_eq!(&dst[..1], b"c");
+
+    // The destination fills before the sentinel, across the wrap
+    assert_eq!(tx.push_slice_until(b"defghi\n", b'\n'), 7);
+    assert_eq!(rx.pull_slice_until(&mut dst[..4], b'\n'), (4, false));
+    assert_eq!(&dst[..4], b"defg");
+    assert_eq!(rx.pull_slice_until(&mut dst, b'\n'), (3, true));
+    assert_eq!(&dst[..3], b"hi\n");
+    assert_eq!(rx.pull_slice_until(&mut dst, b'\n'), (0, false));
+}
+
+#[test]
+fn suggested_capacity() {
+    use ringbuffer_spsc::suggested_capacity;
+
+    assert_eq!(suggested_capacity(48_000.0, 0.05), 4096);
+    assert_eq!(suggested_capacity(1000.0, 1.024), 1024);
+    assert_eq!(suggested_capacity(1000.0, 1.025), 2048);
+    assert_eq!(suggested_capacity(0.0, 1.0), 1);
+}
+
+#[test]
+fn push_with_space() {
+    use ringbuffer_spsc::PushOk;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(tx.push_with_space(0), Ok(PushOk::StoredWithSpace(3)));
+    assert_eq!(tx.push_with_space(1), Ok(PushOk::StoredWithSpace(2)));
+    assert_eq!(tx.push_with_space(2), Ok(PushOk::StoredWithSpace(1)));
+    assert_eq!(tx.push_with_space(3), Ok(PushOk::StoredNowFull));
+    assert_eq!(tx.push_with_space(4), Err(4));
+
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.pull(), Some(1));
+    assert_eq!(tx.push_with_space(4), Ok(PushOk::StoredWithSpace(1)));
+    assert_eq!(tx.push_with_space(5), Ok(PushOk::StoredNowFull));
+}
+
+#[test]
+fn push_with() {
+    use std::cell::Cell;
+
+    let (mut tx, mut rx) = RingBuffer::<Vec<u8>, 2>::init();
+    let constructed = Cell::new(0);
+    let expensive = || {
+        constructed.set(constructed.get() + 1);
+        vec![0u8; 1024]
+    };
+
+    for _ in 0..4 {
+        if !tx.would_block() {
+            assert!(tx.push_with(expensive).is_none());
+        }
+    }
+    assert!(tx.push_with(expensive).is_some());
+    assert_eq!(constructed.get(), 2);
+
+    assert_eq!(rx.pull().map(|v| v.len()), Some(1024));
+    assert!(!tx.would_block());
+    assert!(tx.push_with(expensive).is_none());
+    assert_eq!(constructed.get(), 3);
+}
+
+#[test]
+fn map_reader() {
+    let (mut tx, rx) = RingBuffer::<u32, 4>::init();
+    let mut rx = rx.map(|t| t * 2);
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.pull(), Some(2));
+    assert_eq!(rx.pull(), Some(4));
+    assert_eq!(rx.pull(), None);
+
+    let mut rx = rx.into_inner();
+    assert!(tx.push(3).is_none());
+    assert_eq!(rx.pull(), Some(3));
+}
+
+#[test]
+fn map_writer() {
+    let (tx, mut rx) = RingBuffer::<usize, 2>::init();
+    let mut tx = tx.with(|s: &str| s.len());
+    assert!(tx.push("a").is_none());
+    assert!(tx.push("bb").is_none());
+    assert_eq!(tx.push("ccc"), Some("ccc"));
+
+    assert_eq!(rx.pull(), Some(1));
+    assert!(tx.push("ccc").is_none());
+    assert_eq!(rx.pull(), Some(2));
+    assert_eq!(rx.pull(), Some(3));
+
+    let mut tx = tx.into_inner();
+    assert!(tx.push(4).is_none());
+    assert_eq!(rx.pull(), Some(4));
+}
+
+#[test]
+fn swap_storage() {
+    use std::mem::MaybeUninit;
+
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        if i < 3 {
+            assert!(rx.pull().is_some());
+        }
+    }
+
+    let storage = (0..4).map(|_| MaybeUninit::uninit()).collect();
+    let (mut old, len) = RingBuffer::swap_storage(&mut tx, &mut rx, storage);
+    assert_eq!(len, 3);
+    let old: Vec<String> = old[..len]
+        .iter_mut()
+        .map(|t| unsafe { t.assume_init_read() })
+        .collect();
+    assert_eq!(old, ["3", "4", "5"]);
+
+    // The new storage starts empty
+    assert!(rx.pull().is_none());
+    for i in 0..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.push(4.to_string()).is_some());
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+}
+
+#[test]
+fn swap_storage_buffered() {
+    use std::mem::MaybeUninit;
+
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    assert!(tx.push("a".to_string()).is_none());
+    assert!(tx.push_buffered("b".to_string()).is_none());
+
+    let storage = (0..4).map(|_| MaybeUninit::uninit()).collect();
+    let (mut old, len) = RingBuffer::swap_storage(&mut tx, &mut rx, storage);
+    assert_eq!(len, 2);
+    for (t, expected) in old[..len].iter_mut().zip(["a", "b"]) {
+        assert_eq!(unsafe { t.assume_init_read() }, expected);
+    }
+
+    // The new storage starts empty, even with nothing flushed afterwards
+    assert!(rx.pull().is_none());
+    assert!(tx.push_buffered("c".to_string()).is_none());
+    tx.flush();
+    assert_eq!(rx.pull().as_deref(), Some("c"));
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn pull_at() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    for i in 0..10 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull_at(), Some((i, i % 4)));
+    }
+    assert!(rx.pull_at().is_none());
+}
+
+#[test]
+fn close_and_drain() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        if i < 2 {
+            assert!(rx.pull().is_some());
+        }
+    }
+
+    assert_eq!(rx.close_and_drain(), ["2", "3", "4", "5"]);
+    // The pushed element is dropped with the ring buffer
+    assert!(tx.push(6.to_string()).is_none());
+}
+
+#[test]
+fn consumer_position() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    assert_eq!(tx.consumer_position(), 0);
+    for i in 0..6 {
+        assert!(tx.push(i).is_none());
+    }
+    for _ in 0..4 {
+        assert!(rx.pull().is_some());
+    }
+    assert_eq!(tx.consumer_position(), 4);
+}
+
+#[test]
+fn peeker() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    let peeker = rx.peeker();
+    assert!(peeker.is_empty());
+    assert!(peeker.peek().is_none());
+
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    // The reader is still available while the peeker is alive
+    let descriptor = rx.descriptor();
+    assert_eq!(peeker.len(), 3);
+    assert_eq!(peeker.peek(), Some(&0));
+    assert_eq!(peeker.peek_at(2), Some(&2));
+    assert_eq!(peeker.peek_at(3), None);
+    assert_eq!(descriptor.len(), peeker.len());
+
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.peeker().peek(), Some(&1));
+}
+
+#[test]
+fn try_init() {
+    assert_eq!(
+        RingBuffer::<u8, 0>::try_init().err(),
+        Some(CapacityError::Zero)
+    );
+    assert!(RingBuffer::<u8, 1>::try_init().is_ok());
+    assert_eq!(
+        RingBuffer::<u8, 3>::try_init().err(),
+        Some(CapacityError::NotPowerOfTwo)
+    );
+    assert_eq!(
+        RingBuffer::<(), { usize::MAX }>::try_init().err(),
+        Some(CapacityError::NotPowerOfTwo)
+    );
+}
+
+#[test]
+#[should_panic(expected = "non-zero power of two")]
+fn init_zero_capacity() {
+    let _ = RingBuffer::<u8, 0>::init();
+}
+
+#[test]
+fn pull_group() {
+    let (mut tx, mut rx) = RingBuffer::<(u8, bool), 8>::init();
+    assert!(rx.pull_group(|t| t.1).is_none());
+
+    // An incomplete group is left untouched
+    for t in [(0, false), (1, false)] {
+        assert!(tx.push(t).is_none());
+    }
+    assert!(rx.pull_group(|t| t.1).is_none());
+    assert_eq!(rx.descriptor().len(), 2);
+
+    for t in [(2, true), (3, true), (4, false)] {
+        assert!(tx.push(t).is_none());
+    }
+    assert_eq!(
+        rx.pull_group(|t| t.1),
+        Some(vec![(0, false), (1, false), (2, true)])
+    );
+    assert_eq!(rx.pull_group(|t| t.1), Some(vec![(3, true)]));
+    assert!(rx.pull_group(|t| t.1).is_none());
+    assert_eq!(rx.pull(), Some((4, false)));
+}
+
+#[test]
+fn scratch() {
+    let (mut tx, mut rx) = RingBuffer::<[u64; 64], 2>::init();
+
+    for i in 0..3 {
+        let scratch = tx.scratch_mut().write([0; 64]);
+        scratch[0] = i;
+        scratch[63] = i + 1;
+        if i < 2 {
+            assert!(unsafe { tx.commit_scratch() });
+        } else {
+            // The buffer is full, the element is retained
+            assert!(!unsafe { tx.commit_scratch() });
+        }
+    }
+
+    for i in 0..3 {
+        let t = rx.pull().unwrap();
+        assert_eq!((t[0], t[63]), (i, i + 1));
+        if i == 0 {
+            assert!(unsafe { tx.commit_scratch() });
+        }
+    }
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn init_padded() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 4>::init_padded();
+    for i in 0..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(tx.push(4), Some(4));
+    for i in 0..4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn push_batch_report() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    assert!(tx.push("first".to_string()).is_none());
+
+    let items = (0..10).map(|i| i.to_string()).collect();
+    let rest = tx.push_batch_report(items);
+    assert_eq!(rest, ["3", "4", "5", "6", "7", "8", "9"]);
+
+    assert_eq!(rx.pull().as_deref(), Some("first"));
+    for i in 0..3 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn peek_fold() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(rx.peek_fold(0, |acc, t| acc + t), 0);
+
+    // Make the elements wrap around the end of the buffer
+    for i in 0..2 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    for i in 1..=4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.peek_fold(0, |acc, t| acc + t), 10);
+    assert_eq!(
+        rx.peek_fold(Vec::new(), |mut v, t| {
+            v.push(*t);
+            v
+        }),
+        [1, 2, 3, 4]
+    );
+
+    for i in 1..=4 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+}
+
+#[test]
+fn mask() {
+    let (_, rx) = RingBuffer::<u8, 1>::init();
+    assert_eq!((rx.mask(), rx.capacity_log2()), (0, 0));
+    let (mut tx, mut rx) = RingBuffer::<u8, 16>::init();
+    assert_eq!(rx.mask(), rx.descriptor().capacity() - 1);
+    assert_eq!(rx.capacity_log2(), 16usize.trailing_zeros());
+
+    for i in 0..20 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(
+            rx.pull_at(),
+            Some((i, (tx.consumer_position() - 1) & rx.mask()))
+        );
+    }
+}
+
+#[test]
+fn writer_drop_publishes() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    for i in 0..3 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    drop(tx);
+    for i in 0..3 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn backpressure() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(tx.backpressure_level(), 0);
+    rx.signal_backpressure(2);
+    assert_eq!(tx.backpressure_level(), 2);
+    rx.signal_backpressure(0);
+    assert_eq!(tx.backpressure_level(), 0);
+}
+
+#[test]
+fn pull_view() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    assert!(rx.pull_view(4).is_none());
+
+    // Make the elements wrap around the end of the buffer
+    for i in 0..7 {
+        assert!(tx.push(i.to_string()).is_none());
+        if i < 3 {
+            assert!(rx.pull().is_some());
+        }
+    }
+
+    let mut view = rx.pull_view(3).unwrap();
+    assert_eq!(view.len(), 3);
+    assert_eq!(
+        view.as_slices(),
+        (
+            &["3".to_string()][..],
+            &["4".to_string(), "5".to_string()][..]
+        )
+    );
+    view.set_consumed(2);
+    view.commit();
+
+    // Dropping the view without consuming anything leaves the elements queued
+    drop(rx.pull_view(4).unwrap());
+    assert_eq!(rx.pull().as_deref(), Some("5"));
+    assert_eq!(rx.pull().as_deref(), Some("6"));
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+#[should_panic]
+fn pull_view_overconsume() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert!(tx.push(0).is_none());
+    rx.pull_view(4).unwrap().set_consumed(2);
+}
+
+#[test]
+fn try_consume() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    for t in ["1", "2", "x", "4"] {
+        assert!(tx.push(t.to_string()).is_none());
+    }
+
+    let sum = |acc: u32, t: String| match t.parse::<u32>() {
+        Ok(n) => Ok(acc + n),
+        Err(e) => Err((acc, e, t)),
+    };
+    let (acc, _) = rx.try_consume(0, sum).unwrap_err();
+    assert_eq!(acc, 3);
+    // The failing element and the following ones are still queued
+    assert_eq!(rx.pull().as_deref(), Some("x"));
+    assert_eq!(rx.try_consume(0, sum), Ok(4));
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn push_slice() {
+    let (mut tx, mut rx) = RingBuffer::<f32, 8>::init();
+    // Move the indexes to a non-zero offset so that the free region wraps
+    for i in 0..5 {
+        assert!(tx.push(i as f32).is_none());
+        assert_eq!(rx.pull(), Some(i as f32));
+    }
+
+    let src: Vec<f32> = (0..10).map(|i| i as f32).collect();
+    assert_eq!(tx.push_slice(&src), 8);
+    assert_eq!(tx.push_slice(&src), 0);
+    for i in 0..8 {
+        assert_eq!(rx.pull(), Some(i as f32));
+    }
+    assert_eq!(tx.push_slice(&src[8..]), 2);
+    assert_eq!(rx.pull(), Some(8.0));
+    assert_eq!(rx.pull(), Some(9.0));
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn batch() {
+    let (mut tx, mut rx) = RingBuffer::<u8, 8>::init();
+    assert!(rx.pull_batch::<4>().is_none());
+
+    assert!(tx.push_batch(&[0, 1, 2, 3, 4]));
+    assert!(!tx.push_batch(&[5, 6, 7, 8]));
+    assert!(tx.push_batch(&[5, 6, 7]));
+
+    assert_eq!(rx.pull_batch::<3>(), Some([0, 1, 2]));
+    assert_eq!(rx.pull_batch::<8>(), None);
+    assert_eq!(rx.pull_batch::<5>(), Some([3, 4, 5, 6, 7]));
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn pull_slice() {
+    let (mut tx, mut rx) = RingBuffer::<u16, 8>::init();
+    let mut dst = [0; 16];
+    assert_eq!(rx.pull_slice(&mut dst), 0);
+
+    // Make the available elements wrap around the end of the buffer
+    assert_eq!(tx.push_slice(&[0; 6]), 6);
+    assert_eq!(rx.pull_slice(&mut dst[..6]), 6);
+    let src: Vec<u16> = (0..8).collect();
+    assert_eq!(tx.push_slice(&src), 8);
+
+    assert_eq!(rx.pull_slice(&mut dst[..3]), 3);
+    assert_eq!(dst[..3], [0, 1, 2]);
+    // The destination is larger than the available elements
+    assert_eq!(rx.pull_slice(&mut dst), 5);
+    assert_eq!(dst[..5], [3, 4, 5, 6, 7]);
+    assert!(rx.pull().is_none());
+}
+
+#[test]
+fn peek_ends() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert!(rx.peek_ends().is_none());
+
+    assert!(tx.push(0).is_none());
+    let (first, last) = rx.peek_ends().unwrap();
+    assert!(std::ptr::eq(first, last));
+
+    // Make the elements wrap around the end of the buffer
+    for i in 1..=3 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.peek_ends(), Some((&1, &3)));
+    assert!(tx.push(4).is_none());
+    assert_eq!(rx.peek_ends(), Some((&1, &4)));
+}
+
+#[test]
+fn len_remaining_capacity() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!((tx.remaining_capacity(), rx.len()), (4, 0));
+    assert!(rx.is_empty());
+
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!((tx.remaining_capacity(), rx.len()), (1, 3));
+
+    assert!(tx.push(3).is_none());
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.pull(), Some(1));
+    // The cached read index is refreshed as the buffer appeared full, while
+    // the cached write index is still conclusive for the reader
+    assert_eq!((tx.remaining_capacity(), rx.len()), (2, 1));
+    assert_eq!(rx.pull(), Some(2));
+    assert_eq!(rx.len(), 1);
+    assert!(!rx.is_empty());
+}
+
+#[test]
+fn capacity_log2() {
+    fn check<const N: usize>() {
+        assert_eq!(RingBuffer::<u8, N>::CAPACITY_LOG2, N.trailing_zeros());
+        let (_, rx) = RingBuffer::<u8, N>::init();
+        assert_eq!(rx.capacity_log2(), N.trailing_zeros());
+        assert_eq!(1 << rx.capacity_log2(), N);
+    }
+    check::<1>();
+    check::<2>();
+    check::<64>();
+    check::<4096>();
+}
+
+#[test]
+fn laps() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    assert_eq!(rx.laps(), 0);
+    for i in 0..20 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+        assert_eq!(rx.laps(), (i + 1) / 8);
+    }
+    assert_eq!(rx.laps(), 2);
+}
+
+#[test]
+fn watermarks() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    let high = Arc::new(AtomicUsize::new(0));
+    let low = Arc::new(AtomicUsize::new(0));
+    let h = high.clone();
+    tx.on_high_watermark(6, move || {
+        h.fetch_add(1, Ordering::Relaxed);
+    });
+    let l = low.clone();
+    rx.on_low_watermark(2, move || {
+        l.fetch_add(1, Ordering::Relaxed);
+    });
+
+    for round in 1..=3 {
+        for i in 0..8 {
+            assert!(tx.push(i).is_none());
+            // Fires only when the level is first reached
+            assert_eq!(high.load(Ordering::Relaxed), round - (i < 5) as usize);
+        }
+        for i in 0..8 {
+            assert_eq!(rx.pull(), Some(i));
+            assert_eq!(low.load(Ordering::Relaxed), round - (i < 5) as usize);
+        }
+    }
+}
+
+#[test]
+fn disconnection() {
+    use ringbuffer_spsc::PullError;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    let descriptor = rx.descriptor();
+    assert!(tx.is_reader_alive());
+    assert!(rx.is_writer_alive());
+    assert_eq!(rx.try_pull(), Err(PullError::Empty));
+
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+    }
+    drop(tx);
+    assert!(!rx.is_writer_alive());
+    for i in 0..3 {
+        assert_eq!(rx.try_pull(), Ok(i));
+    }
+    assert_eq!(rx.try_pull(), Err(PullError::Disconnected));
+    drop(descriptor);
+
+    let (tx, rx) = RingBuffer::<usize, 8>::init();
+    drop(rx);
+    assert!(!tx.is_reader_alive());
+}
+
+#[test]
+#[allow(clippy::unnecessary_cast)]
+fn raw_write_index() {
+    use std::sync::atomic::Ordering;
+    const N: usize = 100_000;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init();
+
+    // Another thread acts as the kernel filling the slots
+    let kernel = std::thread::spawn(move || {
+        let (ptr, idx_r) = (tx.as_mut_ptr(), tx.descriptor());
+        let idx_w = unsafe { tx.raw_write_index() };
+        let mut w = idx_w.load(Ordering::Relaxed);
+        let mut i = 0;
+        while i < N {
+            let free = 16 - idx_r.len();
+            let k = free.min(N - i).min(3);
+            for j in 0..k {
+                let idx = w.wrapping_add(j as _) as usize & 15;
+                unsafe { ptr.add(idx).write(i + j) };
+            }
+            w = w.wrapping_add(k as _);
+            idx_w.store(w, Ordering::Release);
+            i += k;
+            if k == 0 {
+                std::thread::yield_now();
+            }
+        }
+    });
+
+    for i in 0..N {
+        loop {
+            if let Some(t) = rx.pull() {
+                assert_eq!(t, i);
+                break;
+            }
+            std::thread::yield_now();
+        }
+    }
+    kernel.join().unwrap();
+}
+
+#[test]
+fn peek_slice_consume() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    assert!(rx.peek_slice().is_empty());
+    // Move the indexes so that the data wraps around
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        assert!(rx.pull().is_some());
+    }
+    for i in 0..5 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+
+    assert_eq!(rx.peek_slice(), ["0", "1"]);
+    assert_eq!(rx.len(), 5);
+    rx.consume(1);
+    assert_eq!(rx.peek_slice(), ["1"]);
+    rx.consume(1);
+    assert_eq!(rx.peek_slice(), ["2", "3", "4"]);
+    rx.consume(3);
+    assert!(rx.peek_slice().is_empty());
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+#[should_panic(expected = "cannot consume 3 elements")]
+fn consume_too_many() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    assert!(tx.push(0).is_none());
+    assert!(tx.push(1).is_none());
+    rx.consume(3);
+}
+
+#[test]
+fn writable_slice_commit() {
+    use std::mem::MaybeUninit;
+
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    // Move the indexes so that the free space wraps around
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        assert!(rx.pull().is_some());
+    }
+
+    let run = tx.writable_slice();
+    assert_eq!(run.len(), 2);
+    run[0] = MaybeUninit::new("a".to_string());
+    unsafe { tx.commit(1) };
+    let run = tx.writable_slice();
+    assert_eq!(run.len(), 1);
+    run[0] = MaybeUninit::new("b".to_string());
+    unsafe { tx.commit(1) };
+    let run = tx.writable_slice();
+    assert_eq!(run.len(), 6);
+    for (i, slot) in run[..3].iter_mut().enumerate() {
+        *slot = MaybeUninit::new(i.to_string());
+    }
+    unsafe { tx.commit(3) };
+
+    for s in ["a", "b", "0", "1", "2"] {
+        assert_eq!(rx.pull().as_deref(), Some(s));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+#[should_panic(expected = "cannot commit 3 elements")]
+fn commit_too_many() {
+    let (mut tx, _rx) = RingBuffer::<usize, 2>::init();
+    assert_eq!(tx.writable_slice().len(), 2);
+    unsafe { tx.commit(3) };
+}
+
+#[test]
+fn with_front() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    assert_eq!(rx.with_front(|_, _| ()), None);
+    for s in ["skip", "keep", "pop"] {
+        assert!(tx.push(s.to_string()).is_none());
+    }
+
+    // Inspect then decide, the borrow is contained in the closure
+    let consumed = rx.with_front(|t, front| {
+        let skip = t == "skip";
+        if skip {
+            front.consume();
+        }
+        skip
+    });
+    assert_eq!(consumed, Some(true));
+    assert_eq!(rx.with_front(|t, _| t.clone()).as_deref(), Some("keep"));
+    assert_eq!(rx.len(), 2);
+
+    assert_eq!(rx.pop_if(|t| t == "pop"), None);
+    assert_eq!(rx.pop_if(|t| t == "keep").as_deref(), Some("keep"));
+    assert_eq!(rx.pop_if(|t| t == "pop").as_deref(), Some("pop"));
+    assert_eq!(rx.pop_if(|_| true), None);
+
+    for s in ["a", "bb", "ccc"] {
+        assert!(tx.push(s.to_string()).is_none());
+    }
+    assert_eq!(rx.inspect_front(String::len), Some(1));
+    assert_eq!(rx.inspect_front(String::len), Some(1));
+    assert_eq!(rx.peek_commit(|t| t == "bb"), Some(false));
+    assert_eq!(rx.peek_commit(|t| t == "a"), Some(true));
+    assert_eq!(rx.peek_commit(|t| t == "bb"), Some(true));
+    assert_eq!(rx.inspect_front(String::len), Some(3));
+    assert_eq!(rx.len(), 1);
+    assert_eq!(rx.peek_commit(|_| true), Some(true));
+    assert_eq!(rx.inspect_front(String::len), None);
+    assert_eq!(rx.peek_commit(|_| true), None);
+}
+
+#[test]
+fn drain() {
+    const K: usize = 6;
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    for i in 0..K {
+        assert!(tx.push(i).is_none());
+    }
+    let mut drain = rx.drain();
+    assert_eq!(drain.len(), K);
+    assert_eq!(drain.next(), Some(0));
+    // Elements pushed afterwards are not drained
+    assert!(tx.push(K).is_none());
+    assert_eq!(drain.collect::<Vec<_>>(), (1..K).collect::<Vec<_>>());
+    assert_eq!(rx.drain().collect::<Vec<_>>(), [K]);
+    assert!(rx.is_empty());
+    assert_eq!(rx.drain().next(), None);
+}
+
+#[test]
+fn try_push() {
+    use ringbuffer_spsc::PushError;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    assert_eq!(tx.try_push(0), Ok(()));
+    assert_eq!(tx.try_push(1), Ok(()));
+    assert_eq!(tx.try_push(2), Err(PushError::Full(2)));
+    assert_eq!(tx.push(2), Some(2));
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(tx.try_push(2), Ok(()));
+
+    drop(rx);
+    let err = tx.try_push(3).unwrap_err();
+    assert_eq!(err, PushError::Disconnected(3));
+    assert_eq!(
+        err.to_string(),
+        "ring buffer is full and the reader is gone"
+    );
+    assert_eq!(err.into_inner(), 3);
+}
+
+#[test]
+fn push_buffered_flush() {
+    const K: usize = 5;
+    let (mut tx, mut rx) = RingBuffer::<usize, 8>::init();
+    for i in 0..K {
+        assert!(tx.push_buffered(i).is_none());
+    }
+    // Nothing is visible until the write index is published
+    assert_eq!(rx.pull(), None);
+    tx.flush();
+    for i in 0..K {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+
+    // A full buffer gives the element back
+    for i in 0..8 {
+        assert!(tx.push_buffered(i).is_none());
+    }
+    assert_eq!(tx.push_buffered(8), Some(8));
+    // Dropping the writer publishes the buffered elements
+    drop(tx);
+    assert_eq!(rx.drain().collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+}
+
+#[test]
+fn pull_bounded() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static DROPS: AtomicUsize = AtomicUsize::new(0);
+    #[derive(Debug, PartialEq)]
+    struct Counted(usize);
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPS.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let (mut tx, mut rx) = RingBuffer::<Counted, 16>::init();
+    for i in 0..10 {
+        assert!(tx.push(Counted(i)).is_none());
+    }
+    let front = rx.pull_bounded(3).unwrap();
+    assert_eq!(front.0, 7);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 7);
+    drop(front);
+    assert!(rx.len() <= 3);
+
+    // Within the bound nothing is skipped
+    assert_eq!(rx.pull_bounded(3).map(|c| c.0), Some(8));
+    assert_eq!(rx.pull_bounded(1).map(|c| c.0), Some(9));
+    assert_eq!(rx.pull_bounded(1), None);
+    // A zero lag skips everything
+    assert!(tx.push(Counted(10)).is_none());
+    assert_eq!(rx.pull_bounded(0), None);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 11);
+}
+
+#[test]
+fn init_filled() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_filled(0..8);
+    assert_eq!(rx.len(), 8);
+    for i in 0..8 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+    assert!(tx.push(8).is_none());
+    assert_eq!(rx.pull(), Some(8));
+
+    // Only the first N elements are taken
+    let mut items = 0..20;
+    let (mut tx, mut rx) = RingBuffer::<usize, 16>::init_filled(items.by_ref());
+    assert_eq!(items.next(), Some(16));
+    assert!(tx.is_full());
+    assert_eq!(rx.drain().collect::<Vec<_>>(), (0..16).collect::<Vec<_>>());
+}
+
+#[test]
+fn push_signal() {
+    use ringbuffer_spsc::PushSignal;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(tx.push_signal(0), PushSignal::StoredWake);
+    for i in 1..4 {
+        assert_eq!(tx.push_signal(i), PushSignal::StoredNoWake);
+    }
+    assert_eq!(tx.push_signal(4), PushSignal::Full(4));
+
+    // Only a push into a buffer drained by the reader wakes it up again
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(tx.push_signal(4), PushSignal::StoredNoWake);
+    assert_eq!(rx.drain().count(), 4);
+    assert_eq!(tx.push_signal(5), PushSignal::StoredWake);
+    assert_eq!(tx.push_signal(6), PushSignal::StoredNoWake);
+}
+
+#[test]
+fn push_signal_drop_newest() {
+    use ringbuffer_spsc::{FullPolicy, PushSignal};
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    tx.set_full_policy(FullPolicy::DropNewest);
+    assert_eq!(tx.push_signal(0), PushSignal::StoredWake);
+    assert_eq!(tx.push_signal(1), PushSignal::StoredNoWake);
+    // The element that push() would drop is given back instead
+    assert_eq!(tx.push_signal(2), PushSignal::Full(2));
+    assert_eq!(rx.pull(), Some(0));
+    assert_eq!(rx.pull(), Some(1));
+    assert_eq!(rx.pull(), None);
+    assert_eq!(tx.push_signal(3), PushSignal::StoredWake);
+}
+
+#[test]
+fn relaxed_checks() {
+    let (mut tx, mut rx) = RingBuffer::<usize, 2>::init();
+    let (tx_ref, rx_ref) = (&tx, &rx);
+    assert!(!tx_ref.is_full_relaxed());
+    assert!(rx_ref.is_empty_relaxed());
+
+    assert!(tx.push(0).is_none());
+    assert!(!tx.is_full_relaxed());
+    assert!(!rx.is_empty_relaxed());
+    assert!(tx.push(1).is_none());
+    assert!(tx.is_full_relaxed());
+
+    // Not cached, so they reflect the other side right away
+    assert_eq!(rx.pull(), Some(0));
+    assert!(!tx.is_full_relaxed());
+    assert_eq!(rx.pull(), Some(1));
+    assert!(rx.is_empty_relaxed());
+}
+
+#[test]
+fn pull_signal() {
+    use ringbuffer_spsc::PullSignal;
+
+    let (mut tx, mut rx) = RingBuffer::<usize, 4>::init();
+    assert_eq!(rx.pull_signal(), PullSignal::Empty);
+    for i in 0..4 {
+        assert!(tx.push(i).is_none());
+    }
+    assert_eq!(rx.pull_signal(), PullSignal::RemovedWake(0));
+    assert_eq!(rx.pull_signal(), PullSignal::RemovedNoWake(1));
+
+    // Only a pull from a buffer filled up again wakes the writer up again
+    assert!(tx.push(4).is_none());
+    assert_eq!(rx.pull_signal(), PullSignal::RemovedNoWake(2));
+    assert!(tx.push(5).is_none());
+    assert!(tx.push(6).is_none());
+    assert_eq!(rx.pull_signal(), PullSignal::RemovedWake(3));
+    for i in 4..7 {
+        assert_eq!(rx.pull_signal(), PullSignal::RemovedNoWake(i));
+    }
+    assert_eq!(rx.pull_signal(), PullSignal::Empty);
+}
+
+#[test]
+fn snapshot() {
+    let (mut tx, mut rx) = RingBuffer::<u32, 4>::init();
+    assert!(rx.snapshot().is_empty());
+    // Move the indexes so that the data wraps around
+    for i in 0..3 {
+        assert!(tx.push(i).is_none());
+        assert_eq!(rx.pull(), Some(i));
+    }
+    for i in 10..13 {
+        assert!(tx.push(i).is_none());
+    }
+    let snapshot = rx.snapshot();
+    assert_eq!(snapshot.len(), 3);
+    assert_eq!(snapshot.iter().copied().collect::<Vec<_>>(), [10, 11, 12]);
+    assert_eq!(format!("{snapshot:?}"), "[10, 11, 12]");
+
+    // Nothing has been consumed
+    for i in 10..13 {
+        assert_eq!(rx.pull(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn drain_lifo_batch() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    assert!(rx.drain_lifo_batch(4).is_empty());
+    // Move the indexes so that the data wraps around
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert_eq!(rx.drain_lifo_batch(2), ["5", "4"]);
+    assert_eq!(rx.len(), 4);
+
+    // The freed slots are reused by the writer
+    for i in 6..10 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.is_full());
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+    assert_eq!(rx.drain_lifo_batch(3), ["9", "8", "7"]);
+    assert_eq!(rx.drain_lifo_batch(0), Vec::<String>::new());
+    for i in ["1", "2", "3", "6"] {
+        assert_eq!(rx.pull().as_deref(), Some(i));
+    }
+    assert_eq!(rx.pull(), None);
+    assert!(tx.push(10.to_string()).is_none());
+    assert_eq!(rx.drain_lifo_batch(usize::MAX), ["10"]);
+}
+
+#[test]
+fn footprint() {
+    const BYTES: usize = RingBuffer::<u64, 16>::CAPACITY_BYTES;
+    assert_eq!(BYTES, 16 * 8);
+
+    let (tx, rx) = RingBuffer::<[u8; 24], 8>::init();
+    assert_eq!(tx.capacity_bytes(), 8 * 24);
+    assert_eq!(rx.capacity_bytes(), 8 * 24);
+    assert_eq!(tx.overhead_bytes(), rx.overhead_bytes());
+    // At least the reference counts and the two cache-padded indexes
+    assert!(tx.overhead_bytes() >= 2 * std::mem::size_of::<usize>() + 2 * 32);
+
+    // Zero-sized elements take no storage
+    let (tx, _rx) = RingBuffer::<(), 8>::init();
+    assert_eq!(tx.capacity_bytes(), 0);
+}
+
+#[test]
+fn recycle() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    let ptr = tx.as_mut_ptr();
+    for i in 0..3 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+    assert!(tx.push_buffered(3.to_string()).is_none());
+    let storage = RingBuffer::recycle(tx, rx).unwrap();
+
+    // Same allocation, empty and with the indexes reset
+    let (mut tx, mut rx) = RingBuffer::init_from_storage(storage);
+    assert_eq!(tx.as_mut_ptr(), ptr);
+    assert!(rx.is_empty());
+    assert!(tx.is_reader_alive());
+    assert!(rx.is_writer_alive());
+    for i in 0..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert!(tx.is_full());
+    assert_eq!(unsafe { &*ptr }, "0");
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+
+    // Not reclaimed while still referenced
+    let descriptor = rx.descriptor();
+    assert!(RingBuffer::recycle(tx, rx).is_none());
+    drop(descriptor);
+}
+
+#[test]
+fn push_iter() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    let mut empty = std::iter::empty();
+    assert_eq!(tx.push_iter(&mut empty), 0);
+
+    let mut iter = (0..6).map(|i| i.to_string());
+    assert_eq!(tx.push_iter(&mut iter), 4);
+    assert!(tx.is_full());
+    // A full buffer takes nothing from the iterator
+    assert_eq!(tx.push_iter(&mut iter), 0);
+    assert_eq!(rx.pull().as_deref(), Some("0"));
+    assert_eq!(tx.push_iter(&mut iter), 1);
+    assert_eq!(iter.collect::<Vec<_>>(), ["5"]);
+    for i in 1..5 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert_eq!(rx.pull(), None);
+}
+
+#[test]
+fn peek_n() {
+    let (mut tx, mut rx) = RingBuffer::<String, 8>::init();
+    assert_eq!(rx.peek_n(0), Some((&[][..], &[][..])));
+    assert_eq!(rx.peek_n(1), None);
+    // Move the indexes so that the data wraps around
+    for i in 0..6 {
+        assert!(tx.push(i.to_string()).is_none());
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    for i in 0..5 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    let (a, b) = rx.peek_n(2).unwrap();
+    assert_eq!((a, b), (&["0", "1"].map(String::from)[..], &[][..]));
+    let (a, b) = rx.peek_n(4).unwrap();
+    assert_eq!(a, ["0", "1"]);
+    assert_eq!(b, ["2", "3"]);
+    assert_eq!(rx.peek_n(5).map(|(a, b)| a.len() + b.len()), Some(5));
+    assert_eq!(rx.peek_n(6), None);
+
+    // Nothing has been consumed
+    for i in 0..5 {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+}
+
+#[test]
+fn replace_head() {
+    let (mut tx, mut rx) = RingBuffer::<String, 4>::init();
+    assert_eq!(rx.replace_head("x".to_string()), None);
+    for i in 1..4 {
+        assert!(tx.push(i.to_string()).is_none());
+    }
+    assert_eq!(rx.replace_head(9.to_string()), Some(1.to_string()));
+    assert_eq!(rx.len(), 3);
+    for i in [9, 2, 3] {
+        assert_eq!(rx.pull(), Some(i.to_string()));
+    }
+    assert_eq!(rx.pull(), None);
+}